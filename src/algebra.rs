@@ -0,0 +1,480 @@
+use crate::pattern::StridePattern;
+use crate::RangeStepIdx;
+use num::integer::Integer;
+use std::iter::{FusedIterator, Peekable};
+use std::ops::{Range, RangeInclusive};
+
+/// The result of a set operation on the stride patterns.
+///
+/// See the [`StridePattern::intersection()`], [`StridePattern::union()`]
+/// and [`StridePattern::difference()`] methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PatternSet {
+    /// The result is a single pattern.
+    Pattern(StridePattern),
+    /// The result is not a single pattern, the indices are merged from both patterns.
+    Merged(Merged),
+}
+
+impl IntoIterator for PatternSet {
+    type Item = usize;
+    type IntoIter = MergedIter;
+
+    #[inline]
+    fn into_iter(self) -> MergedIter {
+        match self {
+            PatternSet::Pattern(pattern) => Merged::new(pattern, EMPTY, SetOp::Union).iter(),
+            PatternSet::Merged(merged) => merged.iter(),
+        }
+    }
+}
+
+/// A pattern without indices.
+const EMPTY: StridePattern = StridePattern::new(0, 0, 1, 0);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum SetOp {
+    Intersection,
+    Union,
+    Difference,
+}
+
+impl SetOp {
+    #[inline]
+    fn apply(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            SetOp::Intersection => in_a && in_b,
+            SetOp::Union => in_a || in_b,
+            SetOp::Difference => in_a && !in_b,
+        }
+    }
+}
+
+/// The boundaries of the sorted inclusive ranges, `true` for the start of a range.
+#[derive(Clone, Debug)]
+struct Bounds<I> {
+    ranges: I,
+    end: Option<usize>,
+}
+
+impl<I> Iterator for Bounds<I>
+where
+    I: Iterator<Item = RangeInclusive<usize>>,
+{
+    type Item = (usize, bool);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, bool)> {
+        if let Some(end) = self.end.take() {
+            return Some((end, false));
+        }
+        let range = self.ranges.next()?;
+        self.end = Some(range.end() + 1);
+        Some((*range.start(), true))
+    }
+}
+
+/// The result of a set operation on two sequences of sorted inclusive ranges, as half-open ranges.
+#[derive(Clone, Debug)]
+struct Sweep<A, B>
+where
+    A: Iterator<Item = RangeInclusive<usize>>,
+    B: Iterator<Item = RangeInclusive<usize>>,
+{
+    a: Peekable<Bounds<A>>,
+    b: Peekable<Bounds<B>>,
+    in_a: bool,
+    in_b: bool,
+    op: SetOp,
+}
+
+impl<A, B> Sweep<A, B>
+where
+    A: Iterator<Item = RangeInclusive<usize>>,
+    B: Iterator<Item = RangeInclusive<usize>>,
+{
+    #[inline]
+    fn new(a: A, b: B, op: SetOp) -> Self {
+        Sweep {
+            a: Bounds { ranges: a, end: None }.peekable(),
+            b: Bounds { ranges: b, end: None }.peekable(),
+            in_a: false,
+            in_b: false,
+            op,
+        }
+    }
+
+    /// Processes all boundaries at the next position, returns the position.
+    #[inline]
+    fn step(&mut self) -> Option<usize> {
+        let pos = match (self.a.peek(), self.b.peek()) {
+            (Some(&(a, _)), Some(&(b, _))) => a.min(b),
+            (Some(&(a, _)), None) => a,
+            (None, Some(&(b, _))) => b,
+            (None, None) => return None,
+        };
+        while let Some((_, start)) = self.a.next_if(|&(at, _)| at == pos) {
+            self.in_a = start;
+        }
+        while let Some((_, start)) = self.b.next_if(|&(at, _)| at == pos) {
+            self.in_b = start;
+        }
+        Some(pos)
+    }
+}
+
+impl<A, B> Iterator for Sweep<A, B>
+where
+    A: Iterator<Item = RangeInclusive<usize>>,
+    B: Iterator<Item = RangeInclusive<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let start = loop {
+            let pos = self.step()?;
+            if self.op.apply(self.in_a, self.in_b) {
+                break pos;
+            }
+        };
+        loop {
+            let pos = self.step()?;
+            if !self.op.apply(self.in_a, self.in_b) {
+                return Some(start..pos);
+            }
+        }
+    }
+}
+
+/// The runs of consecutive indices of a pattern.
+#[derive(Clone, Debug)]
+struct Runs(RangeStepIdx);
+
+impl Iterator for Runs {
+    type Item = RangeInclusive<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<RangeInclusive<usize>> {
+        self.0.next_run().map(|(start, end)| start..=end)
+    }
+}
+
+/// A set operation on two stride patterns whose result is not a single pattern.
+///
+/// See the [`PatternSet`] type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Merged {
+    a: StridePattern,
+    b: StridePattern,
+    op: SetOp,
+}
+
+impl Merged {
+    #[inline]
+    fn new(a: StridePattern, b: StridePattern, op: SetOp) -> Self {
+        Merged { a, b, op }
+    }
+
+    /// Creates an iterator over the sorted indices of the result, without duplicates.
+    #[inline]
+    pub fn iter(&self) -> MergedIter {
+        MergedIter {
+            sweep: Sweep::new(Runs(self.a.iter()), Runs(self.b.iter()), self.op),
+            range: 0..0,
+        }
+    }
+}
+
+impl IntoIterator for Merged {
+    type Item = usize;
+    type IntoIter = MergedIter;
+
+    #[inline]
+    fn into_iter(self) -> MergedIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the sorted indices of a set operation on two stride patterns.
+///
+/// See the [`PatternSet`] type.
+#[derive(Clone, Debug)]
+pub struct MergedIter {
+    sweep: Sweep<Runs, Runs>,
+    range: Range<usize>,
+}
+
+impl Iterator for MergedIter {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.range.is_empty() {
+            self.range = self.sweep.next()?;
+        }
+        self.range.next()
+    }
+}
+
+impl FusedIterator for MergedIter {}
+
+/// The valid pattern in the form of the periodic blocks cut at the end.
+#[derive(Clone, Copy, Debug)]
+struct Periodic {
+    start: usize,
+    stop: usize,
+    step: usize,
+    /// The end of the last block that fits.
+    cut: usize,
+}
+
+impl Periodic {
+    #[inline]
+    fn new(pattern: &StridePattern) -> Option<Self> {
+        let StridePattern { start, stop, step, end } = *pattern;
+        (start <= stop && stop < step && step <= end).then(|| Periodic {
+            start,
+            stop,
+            step,
+            cut: end / step * step,
+        })
+    }
+
+    /// Checks whether the pattern has indices at or after the position.
+    #[inline]
+    fn reaches(&self, pos: usize) -> bool {
+        self.cut - self.step + self.stop >= pos
+    }
+
+    /// The blocks in `0..period`, the period is a multiple of the step.
+    #[inline]
+    fn blocks(&self, period: usize) -> impl Iterator<Item = RangeInclusive<usize>> {
+        let Periodic { start, stop, step, .. } = *self;
+        (0..period / step).map(move |k| k * step + start..=k * step + stop)
+    }
+
+    #[inline]
+    fn same_blocks(&self, other: &Periodic) -> bool {
+        (self.start, self.stop, self.step) == (other.start, other.stop, other.step)
+    }
+
+    /// Returns the pattern of the blocks cut at `cut`, `None` if a block is cut in the middle.
+    #[inline]
+    fn cut_at(&self, cut: usize) -> Option<StridePattern> {
+        let (blocks, rest) = cut.div_rem(&self.step);
+        let blocks = if rest <= self.start {
+            blocks
+        } else if rest > self.stop {
+            blocks + 1
+        } else {
+            return None;
+        };
+        if blocks == 0 {
+            return Some(EMPTY);
+        }
+        let end = blocks.checked_mul(self.step)?;
+        Some(StridePattern::new(self.start, self.stop, self.step, end))
+    }
+}
+
+/// The most blocks of one lcm period that are compared to find a single pattern.
+const MAX_BLOCKS: usize = 1 << 16;
+
+/// Returns the blocks of the set operation on the infinite patterns, if they form a single pattern.
+///
+/// The operation is periodic with the lcm of the steps, the blocks of one period are compared.
+/// Returns `None` if they do not form a single pattern, if the period is longer than the patterns
+/// or if it has more than [`MAX_BLOCKS`] blocks.
+fn periodic(a: &Periodic, b: &Periodic, op: SetOp) -> Option<Option<Periodic>> {
+    let period = a.step.checked_mul(b.step / a.step.gcd(&b.step))?;
+    if period > a.cut.max(b.cut) || period / a.step + period / b.step > MAX_BLOCKS {
+        return None;
+    }
+    single_pattern(Sweep::new(a.blocks(period), b.blocks(period), op), period)
+}
+
+/// Returns the common blocks of the infinite patterns, if they form a single pattern.
+///
+/// A block of `a` and a block of `b` overlap if the offset between their starts is in `1 - wa..wb`,
+/// each offset of the residue class of the starts modulo the gcd of the steps
+/// is taken by one pair of blocks in a period, found by the CRT.
+/// The time is proportional to the number of common blocks in a period, not to the period.
+fn intersection_periodic(a: &Periodic, b: &Periodic) -> Option<Option<Periodic>> {
+    let gcd = a.step.gcd(&b.step);
+    let period = a.step.checked_mul(b.step / gcd)?;
+    if period > a.cut.max(b.cut) {
+        return None;
+    }
+    let (wa, wb) = ((a.stop - a.start + 1) as i128, (b.stop - b.start + 1) as i128);
+    let diff = a.start as i128 - b.start as i128;
+    let gcd_i = gcd as i128;
+    let first = 1 - wa + (diff - 1 + wa).rem_euclid(gcd_i);
+    if first > wb - 1 {
+        return Some(None);
+    }
+    let count = usize::try_from((wb - 1 - first) / gcd_i + 1).ok().filter(|&count| count <= MAX_BLOCKS)?;
+    // The block `i` of `a` starts at the offset `first` from a block of `b` if `i * a.step ≡ first - diff`
+    // modulo `b.step`, the next offset adds the inverse of `a.step / gcd` modulo `b.step / gcd` to `i`.
+    let modulus = (b.step / gcd) as u128;
+    let inverse = ((a.step / gcd) as i128).extended_gcd(&(modulus as i128)).x.rem_euclid(modulus as i128) as u128;
+    let mut block = ((first - diff) / gcd_i).rem_euclid(modulus as i128) as u128 * inverse % modulus;
+    let mut offset = first;
+    let mut ranges: Vec<Range<usize>> = (0..count)
+        .map(|_| {
+            let pos = a.start as i128 + (block * a.step as u128) as i128;
+            let range = (pos + (-offset).max(0)) as usize..(pos + (wa - 1).min(wb - 1 - offset) + 1) as usize;
+            block = (block + inverse) % modulus;
+            offset += gcd_i;
+            range
+        })
+        .collect();
+    ranges.sort_unstable_by_key(|range| range.start);
+    let merged = ranges.into_iter().fold(Vec::<Range<usize>>::new(), |mut merged, range| {
+        match merged.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => merged.push(range),
+        }
+        merged
+    });
+    single_pattern(merged, period)
+}
+
+/// Returns the sorted ranges of one period as a pattern, if they have the same width and are equally spaced.
+fn single_pattern(ranges: impl IntoIterator<Item = Range<usize>>, period: usize) -> Option<Option<Periodic>> {
+    let mut ranges = ranges.into_iter();
+    let Some(first) = ranges.next() else {
+        return Some(None);
+    };
+    let width = first.len();
+    let mut count = 1_usize;
+    let mut step = period;
+    let mut next = first.start;
+    for range in ranges {
+        if count == 1 {
+            step = range.start - first.start;
+        }
+        next = next.checked_add(step)?;
+        if range.len() != width || range.start != next {
+            return None;
+        }
+        count += 1;
+    }
+    (step.checked_mul(count) == Some(period) && first.end <= step).then_some(Some(Periodic {
+        start: first.start,
+        stop: first.end - 1,
+        step,
+        cut: usize::MAX,
+    }))
+}
+
+impl StridePattern {
+    /// Returns the indices that are in both patterns.
+    ///
+    /// The result is a single pattern if the common blocks repeat with the lcm of the steps
+    /// and end on a block boundary, otherwise the merged indices.
+    /// The end of a resulting pattern is the smallest multiple of its step that gives the same indices.
+    /// The common blocks are found from the offsets between the blocks of both patterns,
+    /// in the time proportional to their number in the lcm of the steps.
+    /// The union and the difference compare the blocks of the lcm of the steps,
+    /// past 65536 blocks in the lcm, or 65536 common blocks, the result is merged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{PatternSet, StridePattern};
+    ///
+    /// let a = StridePattern::new(0, 1, 4, 24);
+    /// let b = StridePattern::new(0, 3, 8, 24);
+    /// let PatternSet::Pattern(both) = a.intersection(&b) else { panic!() };
+    /// assert_eq!(both, StridePattern::new(0, 1, 8, 24));
+    ///
+    /// let c = StridePattern::new(1, 1, 3, 24);
+    /// let merged: Vec<usize> = a.intersection(&c).into_iter().collect();
+    /// assert_eq!(merged, [1, 4, 13, 16]);
+    ///
+    /// ```
+    pub fn intersection(&self, other: &Self) -> PatternSet {
+        let (Some(a), Some(b)) = (Periodic::new(self), Periodic::new(other)) else {
+            return PatternSet::Pattern(EMPTY);
+        };
+        match intersection_periodic(&a, &b).map(|blocks| blocks.map_or(Some(EMPTY), |blocks| blocks.cut_at(a.cut.min(b.cut)))) {
+            Some(Some(pattern)) => PatternSet::Pattern(pattern),
+            _ => PatternSet::Merged(Merged::new(*self, *other, SetOp::Intersection)),
+        }
+    }
+
+    /// Returns the indices that are in any of the patterns.
+    ///
+    /// See the [`intersection()`](Self::intersection) method for the form of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{PatternSet, StridePattern};
+    ///
+    /// let a = StridePattern::new(0, 1, 4, 24);
+    /// let b = StridePattern::new(2, 3, 4, 24);
+    /// let PatternSet::Pattern(any) = a.union(&b) else { panic!() };
+    /// assert_eq!(any, StridePattern::new(0, 3, 4, 24));
+    ///
+    /// ```
+    pub fn union(&self, other: &Self) -> PatternSet {
+        let (a, b) = match (Periodic::new(self), Periodic::new(other)) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(_), None) => return PatternSet::Pattern(*self),
+            (None, _) => return PatternSet::Pattern(*other),
+        };
+        let (long, short) = if a.cut >= b.cut { (a, b) } else { (b, a) };
+        if long.reaches(short.cut) {
+            return self.combine_with_tail(other, a, b, SetOp::Union, a.cut >= b.cut);
+        }
+        self.combine(other, a, b, SetOp::Union, short.cut)
+    }
+
+    /// Returns the indices of the pattern that are not in the other pattern.
+    ///
+    /// See the [`intersection()`](Self::intersection) method for the form of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{PatternSet, StridePattern};
+    ///
+    /// let a = StridePattern::new(0, 3, 8, 24);
+    /// let b = StridePattern::new(0, 1, 4, 24);
+    /// let PatternSet::Pattern(rest) = a.difference(&b) else { panic!() };
+    /// assert_eq!(rest, StridePattern::new(2, 3, 8, 24));
+    ///
+    /// ```
+    pub fn difference(&self, other: &Self) -> PatternSet {
+        let (a, b) = match (Periodic::new(self), Periodic::new(other)) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(_), None) => return PatternSet::Pattern(*self),
+            (None, _) => return PatternSet::Pattern(EMPTY),
+        };
+        if a.cut > b.cut && a.reaches(b.cut) {
+            return self.combine_with_tail(other, a, b, SetOp::Difference, true);
+        }
+        self.combine(other, a, b, SetOp::Difference, a.cut.min(b.cut))
+    }
+
+    /// Combines the patterns whose indices below the cut are all the indices of the result.
+    #[inline]
+    fn combine(&self, other: &Self, a: Periodic, b: Periodic, op: SetOp, cut: usize) -> PatternSet {
+        match periodic(&a, &b, op).map(|blocks| blocks.map_or(Some(EMPTY), |blocks| blocks.cut_at(cut))) {
+            Some(Some(pattern)) => PatternSet::Pattern(pattern),
+            _ => PatternSet::Merged(Merged::new(*self, *other, op)),
+        }
+    }
+
+    /// Combines the patterns when one of them continues after the end of the other one,
+    /// the result is a single pattern only if it is the longer pattern.
+    #[inline]
+    fn combine_with_tail(&self, other: &Self, a: Periodic, b: Periodic, op: SetOp, self_longer: bool) -> PatternSet {
+        let (pattern, long) = if self_longer { (self, a) } else { (other, b) };
+        match periodic(&a, &b, op) {
+            Some(Some(blocks)) if blocks.same_blocks(&long) => PatternSet::Pattern(*pattern),
+            _ => PatternSet::Merged(Merged::new(*self, *other, op)),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::overflow::Spans;
+use num_convert::TryFromByAdd;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+/// An iterator over the blocks of a cyclic iterator, one inclusive range per block.
+///
+/// The blocks follow the order, the limits and the overflow policy of the source iterator.
+/// A block that is partially consumed by the source iterator is output partially,
+/// a block that wraps around the end of the type is output as two ranges.
+/// The ranges are always in the ascending form, also when counting down.
+///
+/// Created by the [`RangeSkip::into_blocks()`](crate::RangeSkip::into_blocks),
+/// [`RangeStep::into_blocks()`](crate::RangeStep::into_blocks),
+/// [`RangeStepIdx::into_blocks()`](crate::RangeStepIdx::into_blocks),
+/// [`Wrapping::into_blocks()`](crate::Wrapping::into_blocks)
+/// and [`Gaps::into_blocks()`](crate::Gaps::into_blocks) methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{range_skip, range_step_idx};
+///
+/// let blocks: Vec<_> = range_skip(0_u8..5, 200).into_blocks().collect();
+/// assert_eq!(blocks, [0..=5, 206..=211]);
+///
+/// let data: Vec<u8> = (0..22).collect();
+/// let mut out = Vec::new();
+/// for block in range_step_idx(0, 2, 7, data.len()).into_blocks() {
+///     out.extend_from_slice(&data[block]);
+/// }
+/// assert_eq!(out, [0, 1, 2, 7, 8, 9, 14, 15, 16]);
+///
+/// ```
+#[derive(Clone, Debug)]
+pub struct Blocks<T> {
+    spans: Spans,
+    marker: PhantomData<T>,
+}
+
+impl<T> Blocks<T> {
+    #[inline]
+    pub(crate) fn new(spans: impl Into<Spans>) -> Self {
+        Blocks {
+            spans: spans.into(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for Blocks<T>
+where
+    T: TryFromByAdd,
+{
+    type Item = RangeInclusive<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (low, high) = self.spans.next_run()?;
+        Some(T::try_from_u128(low)?..=T::try_from_u128(high)?)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.spans.runs_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Blocks<T>
+where
+    T: TryFromByAdd,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (low, high) = self.spans.next_back_run()?;
+        Some(T::try_from_u128(low)?..=T::try_from_u128(high)?)
+    }
+}
+
+impl<T> FusedIterator for Blocks<T> where T: TryFromByAdd {}
@@ -0,0 +1,230 @@
+use crate::try_range_step_idx;
+use std::iter::FusedIterator;
+use std::mem;
+
+/// Implements block slice range methods (start, stop, step) for a slice.
+///
+/// Each block of the pattern is output as one subslice, the stop is inclusive.
+/// The slice implementation covers the vectors, the arrays and the boxed slices,
+/// a deque is not supported since its blocks may wrap around its two halves.
+///
+/// # Examples
+///
+///```
+/// use iter_cyclic::RangeStepBlocks;
+///
+/// // Records of 3 keys and 1 checksum byte, sorts the keys of each record.
+/// let mut records: Vec<u8> = vec![3, 1, 2, 0xa0, 9, 7, 8, 0xb0];
+/// for keys in records.range_step_chunks_mut(0, 2, 4) {
+///     keys.sort_unstable();
+/// }
+/// assert_eq!(records, [1, 2, 3, 0xa0, 7, 8, 9, 0xb0]);
+///
+/// let sums: Vec<u32> = records.range_step_chunks(0, 2, 4).map(|keys| keys.iter().map(|&key| key as u32).sum()).collect();
+/// assert_eq!(sums, [6, 24]);
+///
+///```
+pub trait RangeStepBlocks<T> {
+    /// Returns an iterator over the blocks of the range (start, stop, step) as subslices.
+    fn range_step_chunks(&self, start: usize, stop: usize, step: usize) -> RangeStepChunks<'_, T>;
+
+    /// Returns an iterator over the blocks of the range (start, stop, step) as disjoint mutable subslices.
+    fn range_step_chunks_mut(&mut self, start: usize, stop: usize, step: usize) -> RangeStepChunksMut<'_, T>;
+}
+
+impl<T> RangeStepBlocks<T> for [T] {
+    #[inline]
+    fn range_step_chunks(&self, start: usize, stop: usize, step: usize) -> RangeStepChunks<'_, T> {
+        RangeStepChunks {
+            layout: Layout::new(start, stop, step, self.len()),
+            slice: self,
+        }
+    }
+
+    #[inline]
+    fn range_step_chunks_mut(&mut self, start: usize, stop: usize, step: usize) -> RangeStepChunksMut<'_, T> {
+        RangeStepChunksMut {
+            layout: Layout::new(start, stop, step, self.len()),
+            rest: self,
+            offset: 0,
+        }
+    }
+}
+
+/// The blocks of a pattern that are not consumed yet, counted from the first block of the slice.
+#[derive(Clone, Copy, Debug)]
+struct Layout {
+    start: usize,
+    width: usize,
+    step: usize,
+    front: usize,
+    back: usize,
+}
+
+impl Layout {
+    #[inline]
+    fn new(start: usize, stop: usize, step: usize, len: usize) -> Self {
+        let (width, blocks) = match try_range_step_idx(start, stop, step, len) {
+            Ok(_) => (stop - start + 1, len / step),
+            Err(_) => (0, 0),
+        };
+        Layout {
+            start,
+            width,
+            step,
+            front: 0,
+            back: blocks,
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+
+    /// Returns the index of the first element of the block.
+    #[inline]
+    fn first(&self, block: usize) -> usize {
+        block * self.step + self.start
+    }
+
+    /// Skips n blocks from the front and returns the index of the first element of the next one.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<usize> {
+        if n >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n + 1;
+        Some(self.first(self.front - 1))
+    }
+
+    /// Skips n blocks from the back and returns the index of the first element of the next one.
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<usize> {
+        if n >= self.len() {
+            self.back = self.front;
+            return None;
+        }
+        self.back -= n + 1;
+        Some(self.first(self.back))
+    }
+}
+
+/// An iterator over the blocks of the range (start, stop, step) as subslices.
+///
+/// See the [`RangeStepBlocks::range_step_chunks()`] method.
+#[derive(Debug)]
+pub struct RangeStepChunks<'a, T> {
+    slice: &'a [T],
+    layout: Layout,
+}
+
+impl<'a, T> RangeStepChunks<'a, T> {
+    #[inline]
+    fn chunk(&self, first: usize) -> &'a [T] {
+        &self.slice[first..first + self.layout.width]
+    }
+}
+
+impl<T> Clone for RangeStepChunks<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RangeStepChunks {
+            slice: self.slice,
+            layout: self.layout,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RangeStepChunks<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a [T]> {
+        self.layout.nth(n).map(|first| self.chunk(first))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.layout.len(), Some(self.layout.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeStepChunks<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.layout.nth_back(n).map(|first| self.chunk(first))
+    }
+}
+
+impl<T> ExactSizeIterator for RangeStepChunks<'_, T> {}
+
+impl<T> FusedIterator for RangeStepChunks<'_, T> {}
+
+/// An iterator over the blocks of the range (start, stop, step) as disjoint mutable subslices.
+///
+/// The blocks are split off the slice with `split_at_mut`, so they never alias.
+/// See the [`RangeStepBlocks::range_step_chunks_mut()`] method.
+#[derive(Debug)]
+pub struct RangeStepChunksMut<'a, T> {
+    /// The part of the slice between the consumed blocks at the front and at the back.
+    rest: &'a mut [T],
+    /// The index of the first element of `rest` in the slice.
+    offset: usize,
+    layout: Layout,
+}
+
+impl<'a, T> Iterator for RangeStepChunksMut<'a, T> {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a mut [T]> {
+        let first = self.layout.nth(n)? - self.offset;
+        let (_, rest) = mem::take(&mut self.rest).split_at_mut(first);
+        let (chunk, rest) = rest.split_at_mut(self.layout.width);
+        self.rest = rest;
+        self.offset += first + self.layout.width;
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.layout.len(), Some(self.layout.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeStepChunksMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let first = self.layout.nth_back(n)? - self.offset;
+        let (rest, _) = mem::take(&mut self.rest).split_at_mut(first + self.layout.width);
+        let (rest, chunk) = rest.split_at_mut(first);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for RangeStepChunksMut<'_, T> {}
+
+impl<T> FusedIterator for RangeStepChunksMut<'_, T> {}
@@ -0,0 +1,100 @@
+use crate::{range_step_idx, CyclicError, RangeStepIdx, StridePattern};
+
+/// Returns the indices of the pattern with the end limited to the length of the slice.
+#[inline]
+fn clipped(pattern: &StridePattern, len: usize) -> RangeStepIdx {
+    range_step_idx(pattern.start, pattern.stop, pattern.step, pattern.end.min(len))
+}
+
+/// Returns the paired indices of two patterns, or an error if their numbers differ.
+#[inline]
+fn paired(
+    src: &StridePattern,
+    src_len: usize,
+    dst: &StridePattern,
+    dst_len: usize,
+) -> Result<impl Iterator<Item = (usize, usize)>, CyclicError> {
+    let (src, dst) = (clipped(src, src_len), clipped(dst, dst_len));
+    if src.len() != dst.len() {
+        return Err(CyclicError::LengthMismatch {
+            src: src.len(),
+            dst: dst.len(),
+        });
+    }
+    Ok(src.zip(dst))
+}
+
+/// Copies the elements of the source pattern to the elements of the destination pattern, in order.
+///
+/// The end of each pattern is limited to the length of its slice.
+/// Returns the number of copied elements.
+///
+/// # Errors
+///
+/// [`CyclicError::LengthMismatch`] if the patterns select different numbers of elements,
+/// nothing is copied then.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{copy_range_step, CyclicError, StridePattern};
+///
+/// // The red plane of a planar 2x2 image into the packed RGB image.
+/// let planar: Vec<u8> = vec![1, 2, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0];
+/// let mut packed = vec![0_u8; 12];
+/// let red = StridePattern::new(0, 0, 1, 4);
+/// let count = copy_range_step(&planar, red, &mut packed, StridePattern::new(0, 0, 3, usize::MAX));
+/// assert_eq!(count, Ok(4));
+/// assert_eq!(packed, [1, 0, 0, 2, 0, 0, 3, 0, 0, 4, 0, 0]);
+///
+/// let err = copy_range_step(&planar, red, &mut packed, StridePattern::new(0, 0, 4, usize::MAX));
+/// assert_eq!(err, Err(CyclicError::LengthMismatch { src: 4, dst: 3 }));
+///
+/// ```
+#[inline]
+pub fn copy_range_step<T: Clone>(
+    src: &[T],
+    src_pattern: StridePattern,
+    dst: &mut [T],
+    dst_pattern: StridePattern,
+) -> Result<usize, CyclicError> {
+    let mut count = 0;
+    for (src_idx, dst_idx) in paired(&src_pattern, src.len(), &dst_pattern, dst.len())? {
+        dst[dst_idx].clone_from(&src[src_idx]);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Swaps the elements of two patterns in the same slice, pair by pair in order.
+///
+/// The end of each pattern is limited to the length of the slice.
+/// If the patterns overlap, an element may be moved more than once.
+/// Returns the number of swapped pairs.
+///
+/// # Errors
+///
+/// [`CyclicError::LengthMismatch`] if the patterns select different numbers of elements,
+/// nothing is swapped then.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{swap_range_step, StridePattern};
+///
+/// // RGBA to BGRA.
+/// let mut pixels: Vec<u8> = vec![1, 2, 3, 255, 4, 5, 6, 255];
+/// let count = swap_range_step(&mut pixels, StridePattern::new(0, 0, 4, 8), StridePattern::new(2, 2, 4, 8));
+/// assert_eq!(count, Ok(2));
+/// assert_eq!(pixels, [3, 2, 1, 255, 6, 5, 4, 255]);
+///
+/// ```
+#[inline]
+pub fn swap_range_step<T>(slice: &mut [T], a: StridePattern, b: StridePattern) -> Result<usize, CyclicError> {
+    let mut count = 0;
+    for (a_idx, b_idx) in paired(&a, slice.len(), &b, slice.len())? {
+        slice.swap(a_idx, b_idx);
+        count += 1;
+    }
+    Ok(count)
+}
@@ -0,0 +1,410 @@
+use num::traits::{WrappingAdd, WrappingMul, WrappingSub};
+use num::PrimInt;
+
+/// A double-ended position in a sequence of equal blocks of consecutive offsets.
+///
+/// Block `k` covers the offsets `first + k * period ..= first + k * period + width`,
+/// taken modulo `mask + 1`.
+/// The elements are numbered from zero, `front` and `back` are the indices
+/// of the next elements at both ends (inclusive).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Cursor<U> {
+    first: U,
+    width: U,
+    period: U,
+    mask: U,
+    front: U,
+    back: U,
+    front_val: U,
+    front_end: U,
+    back_val: U,
+    back_start: U,
+    done: bool,
+}
+
+impl<U> Cursor<U>
+where
+    U: PrimInt + WrappingAdd + WrappingSub + WrappingMul,
+{
+    /// Creates a cursor over `blocks` blocks of `width + 1` offsets each.
+    #[inline]
+    pub(crate) fn new(first: U, width: U, period: U, blocks: U) -> Self {
+        Self::masked(first, width, period, blocks, U::max_value())
+    }
+
+    /// Creates a cursor over `blocks` blocks taken modulo `mask + 1`.
+    #[inline]
+    fn masked(first: U, width: U, period: U, blocks: U, mask: U) -> Self {
+        let mut cursor = Cursor {
+            first,
+            width,
+            period,
+            mask,
+            front: U::zero(),
+            back: U::zero(),
+            front_val: first,
+            front_end: first.wrapping_add(&width) & mask,
+            back_val: first,
+            back_start: first,
+            done: false,
+        };
+        match cursor.block_last_index(blocks) {
+            Some(last) => cursor.seek_back(last),
+            None => return Self::empty(),
+        }
+        cursor
+    }
+
+    /// Creates a cursor over the single block `first..=last`.
+    #[inline]
+    fn single(first: U, last: U) -> Self {
+        let width = last - first;
+        Self::new(first, width, width.saturating_add(U::one()), U::one())
+    }
+
+    /// Creates a cursor without elements.
+    #[inline]
+    pub(crate) fn empty() -> Self {
+        Cursor {
+            first: U::zero(),
+            width: U::zero(),
+            period: U::one(),
+            mask: U::max_value(),
+            front: U::zero(),
+            back: U::zero(),
+            front_val: U::zero(),
+            front_end: U::zero(),
+            back_val: U::zero(),
+            back_start: U::zero(),
+            done: true,
+        }
+    }
+
+    /// Returns the index of the last element, `None` if there are no elements.
+    #[inline]
+    pub(crate) fn last_index(&self) -> Option<U> {
+        (!self.done).then_some(self.back)
+    }
+
+    /// Returns the index of the last element of the block `blocks - 1`, `None` if there are no blocks.
+    #[inline]
+    pub(crate) fn block_last_index(&self, blocks: U) -> Option<U> {
+        if blocks == U::zero() {
+            return None;
+        }
+        Some(
+            self.width
+                .checked_add(&U::one())
+                .and_then(|len| (blocks - U::one()).checked_mul(&len))
+                .and_then(|idx| idx.checked_add(&self.width))
+                .unwrap_or_else(U::max_value),
+        )
+    }
+
+    /// Returns the index of the element with the offset `val`, if it is not after the element `last`.
+    ///
+    /// The offsets are not wrapped, only the first pass over the offsets is searched.
+    #[inline]
+    pub(crate) fn index_of(&self, val: U, last: U) -> Option<U> {
+        let rel = val.checked_sub(&self.first)?;
+        let idx = match self.width.checked_add(&U::one()) {
+            Some(len) if rel % self.period <= self.width => (rel / self.period)
+                .checked_mul(&len)?
+                .checked_add(&(rel % self.period))?,
+            Some(_) => return None,
+            None => rel,
+        };
+        (idx <= last).then_some(idx)
+    }
+
+    /// Returns the offset of the element `idx`, if it is not after the element `last`.
+    #[inline]
+    pub(crate) fn offset_at(&self, idx: U, last: U) -> Option<U> {
+        (idx <= last).then(|| {
+            let (start, offset) = self.locate(idx);
+            start.wrapping_add(&offset) & self.mask
+        })
+    }
+
+    /// Returns the distance between the starts of the blocks.
+    #[inline]
+    pub(crate) fn period(&self) -> U {
+        self.period
+    }
+
+    /// Returns the offsets not greater than `max` that are outside of the blocks up to the element `last`:
+    /// the offsets before the first block, between the blocks and after the last block.
+    ///
+    /// The offsets between the blocks are taken modulo `mask + 1` as the blocks,
+    /// there are no offsets after the last block if the blocks wrap.
+    #[inline]
+    pub(crate) fn gaps(&self, last: Option<U>, max: U) -> [Self; 3] {
+        let Some(last) = last else {
+            return [Self::single(U::zero(), max), Self::empty(), Self::empty()];
+        };
+        let head = if self.first > U::zero() {
+            Self::single(U::zero(), self.first - U::one())
+        } else {
+            Self::empty()
+        };
+        let Some(len) = self.width.checked_add(&U::one()) else {
+            return [head, Self::empty(), Self::empty()];
+        };
+        let blocks = last / len;
+        let inner = if self.period > len && blocks > U::zero() {
+            let first = self.first.wrapping_add(&len) & self.mask;
+            Self::masked(first, self.period - len - U::one(), self.period, blocks, self.mask)
+        } else {
+            Self::empty()
+        };
+        let end = blocks
+            .checked_mul(&self.period)
+            .and_then(|start| start.checked_add(&self.first))
+            .and_then(|start| start.checked_add(&(last % len)));
+        let tail = match end {
+            Some(end) if end < max => Self::single(end + U::one(), max),
+            _ => Self::empty(),
+        };
+        [head, inner, tail]
+    }
+
+    /// Returns the number of whole blocks not greater than `max`, ignoring the current end.
+    #[inline]
+    pub(crate) fn blocks_below(&self, max: U) -> U {
+        match self.first.checked_add(&self.width) {
+            Some(end) if end <= max => (max - end) / self.period + U::one(),
+            _ => U::zero(),
+        }
+    }
+
+    /// Returns the index of the last element not greater than `max`, ignoring the current end.
+    #[inline]
+    pub(crate) fn last_index_below(&self, max: U) -> Option<U> {
+        let rel = max.checked_sub(&self.first)?;
+        Some(match self.width.checked_add(&U::one()) {
+            Some(len) => (rel / self.period)
+                .checked_mul(&len)
+                .and_then(|idx| idx.checked_add(&(rel % self.period).min(self.width)))
+                .unwrap_or_else(U::max_value),
+            None => rel,
+        })
+    }
+
+    /// Moves the back to the element `last`, or ends the cursor if it is `None`,
+    /// and takes the offsets modulo `mask + 1`.
+    #[inline]
+    pub(crate) fn set_end(&mut self, last: Option<U>, mask: U) {
+        self.mask = mask;
+        match last {
+            Some(last) if last >= self.front => self.seek_back(last),
+            _ => self.done = true,
+        }
+    }
+
+    /// Checks whether the front has reached the element `last` and the cursor is exhausted.
+    #[inline]
+    pub(crate) fn front_reached(&self, last: U) -> bool {
+        self.done && self.front == last
+    }
+
+    /// Returns the start offset of the block and the position in the block of the element `idx`.
+    #[inline]
+    fn locate(&self, idx: U) -> (U, U) {
+        match self.width.checked_add(&U::one()) {
+            Some(len) => (
+                self.first.wrapping_add(&(idx / len).wrapping_mul(&self.period)) & self.mask,
+                idx % len,
+            ),
+            None => (self.first, idx),
+        }
+    }
+
+    #[inline]
+    fn seek_front(&mut self, idx: U) {
+        let (start, offset) = self.locate(idx);
+        self.front = idx;
+        self.front_val = start.wrapping_add(&offset) & self.mask;
+        self.front_end = start.wrapping_add(&self.width) & self.mask;
+    }
+
+    #[inline]
+    fn seek_back(&mut self, idx: U) {
+        let (start, offset) = self.locate(idx);
+        self.back = idx;
+        self.back_start = start;
+        self.back_val = start.wrapping_add(&offset) & self.mask;
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self) -> Option<U> {
+        if self.done {
+            return None;
+        }
+        let val = self.front_val;
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.front = self.front + U::one();
+            if val == self.front_end {
+                self.front_val = val.wrapping_sub(&self.width).wrapping_add(&self.period) & self.mask;
+                self.front_end = self.front_end.wrapping_add(&self.period) & self.mask;
+            } else {
+                self.front_val = val.wrapping_add(&U::one()) & self.mask;
+            }
+        }
+        Some(val)
+    }
+
+    #[inline]
+    pub(crate) fn next_back(&mut self) -> Option<U> {
+        if self.done {
+            return None;
+        }
+        let val = self.back_val;
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.back = self.back - U::one();
+            if val == self.back_start {
+                self.back_start = self.back_start.wrapping_sub(&self.period) & self.mask;
+                self.back_val = self.back_start.wrapping_add(&self.width) & self.mask;
+            } else {
+                self.back_val = val.wrapping_sub(&U::one()) & self.mask;
+            }
+        }
+        Some(val)
+    }
+
+    #[inline]
+    pub(crate) fn nth(&mut self, n: usize) -> Option<U> {
+        if !self.done {
+            match num::cast::<usize, U>(n).and_then(|n| self.front.checked_add(&n)) {
+                Some(idx) if idx <= self.back => self.seek_front(idx),
+                _ => {
+                    self.front = self.back;
+                    self.done = true;
+                }
+            }
+        }
+        self.next()
+    }
+
+    #[inline]
+    pub(crate) fn nth_back(&mut self, n: usize) -> Option<U> {
+        if !self.done {
+            match num::cast::<usize, U>(n).and_then(|n| self.back.checked_sub(&n)) {
+                Some(idx) if idx >= self.front => self.seek_back(idx),
+                _ => {
+                    self.back = self.front;
+                    self.done = true;
+                }
+            }
+        }
+        self.next_back()
+    }
+
+    /// Returns the first and the last offsets of the consecutive run at the front,
+    /// the run ends at the end of the block, at the back, or where the offsets wrap.
+    #[inline]
+    pub(crate) fn next_run(&mut self) -> Option<(U, U)> {
+        if self.done {
+            return None;
+        }
+        let start = self.front_val;
+        let last = match self.width.checked_add(&U::one()) {
+            Some(len) => self
+                .front
+                .saturating_add(self.width - self.front % len)
+                .min(self.back),
+            None => self.back,
+        };
+        let count = (last - self.front).min(self.mask - start);
+        let idx = self.front + count;
+        if idx == self.back {
+            self.front = idx;
+            self.done = true;
+        } else {
+            self.seek_front(idx + U::one());
+        }
+        Some((start, start + count))
+    }
+
+    /// Returns the first and the last offsets of the consecutive run at the back,
+    /// the run starts at the start of the block, at the front, or where the offsets wrap.
+    #[inline]
+    pub(crate) fn next_back_run(&mut self) -> Option<(U, U)> {
+        if self.done {
+            return None;
+        }
+        let end = self.back_val;
+        let first = match self.width.checked_add(&U::one()) {
+            Some(len) => (self.back - self.back % len).max(self.front),
+            None => self.front,
+        };
+        let count = (self.back - first).min(end);
+        let idx = self.back - count;
+        if idx == self.front {
+            self.back = idx;
+            self.done = true;
+        } else {
+            self.seek_back(idx - U::one());
+        }
+        Some((end - count, end))
+    }
+
+    /// Returns the bounds on the number of the remaining runs.
+    #[inline]
+    pub(crate) fn runs_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let Some(len) = self.width.checked_add(&U::one()) else {
+            return (1, Some(1));
+        };
+        match (self.back / len - self.front / len + U::one()).to_usize() {
+            // Each block wraps at most once.
+            Some(blocks) if self.mask != U::max_value() => (blocks, blocks.checked_mul(2)),
+            Some(blocks) => (blocks, Some(blocks)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        match (self.back - self.front)
+            .checked_add(&U::one())
+            .and_then(|len| len.to_usize())
+        {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl Cursor<usize> {
+    /// Converts the cursor to the `u128` offsets.
+    #[inline]
+    pub(crate) fn widen(&self) -> Cursor<u128> {
+        let mask = if self.mask == usize::MAX {
+            u128::MAX
+        } else {
+            self.mask as u128
+        };
+        Cursor {
+            first: self.first as u128,
+            width: self.width as u128,
+            period: self.period as u128,
+            mask,
+            front: self.front as u128,
+            back: self.back as u128,
+            front_val: self.front_val as u128,
+            front_end: self.front_end as u128,
+            back_val: self.back_val as u128,
+            back_start: self.back_start as u128,
+            done: self.done,
+        }
+    }
+}
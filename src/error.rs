@@ -0,0 +1,168 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+
+/// An error returned by the fallible constructors of the cyclic iterators.
+///
+/// Each variant carries the values that were rejected.
+/// See [`try_range_skip()`](crate::try_range_skip), [`try_range_step()`](crate::try_range_step),
+/// [`try_range_step_idx()`](crate::try_range_step_idx), [`StridedView::try_new()`](crate::StridedView::try_new)
+/// and [`copy_range_step()`](crate::copy_range_step).
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step_idx, CyclicError};
+///
+/// let err = try_range_step_idx(0, 6, 5, 255).unwrap_err();
+/// assert_eq!(err, CyclicError::BlockWiderThanStep { stop: 6, step: 5 });
+/// assert_eq!(err.to_string(), "stop 6 is outside of step 5");
+///
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CyclicError<T = usize> {
+    /// The start of the range is greater than its end.
+    InvertedBounds {
+        /// The lower bound of the range.
+        start: T,
+        /// The upper bound of the range.
+        end: T,
+    },
+    /// The skip value cannot be converted to the output type.
+    SkipConversion {
+        /// The rejected skip value.
+        skip: usize,
+    },
+    /// The block does not fit into one step.
+    BlockWiderThanStep {
+        /// The far end of the block from the start of the steps.
+        stop: T,
+        /// The step of the blocks.
+        step: usize,
+    },
+    /// The step is greater than the length of the iterated space.
+    StepExceedsLength {
+        /// The step of the blocks.
+        step: usize,
+        /// The length of the iterated space.
+        len: usize,
+    },
+    /// Building the iterator overflows the output type.
+    Overflow {
+        /// The lower bound of the range.
+        start: T,
+        /// The upper bound of the range.
+        end: T,
+        /// The skip or step of the blocks.
+        step: usize,
+    },
+    /// The last element of a strided view is outside of the slice.
+    ViewOutOfBounds {
+        /// The index of the last element of the view, `usize::MAX` if it overflows.
+        last: usize,
+        /// The length of the slice.
+        len: usize,
+    },
+    /// Two patterns that are transferred element by element select different numbers of elements.
+    LengthMismatch {
+        /// The number of elements of the source pattern.
+        src: usize,
+        /// The number of elements of the destination pattern.
+        dst: usize,
+    },
+}
+
+impl<T: Display> Display for CyclicError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CyclicError::InvertedBounds { start, end } => write!(f, "start {start} > end {end}"),
+            CyclicError::SkipConversion { skip } => {
+                write!(f, "skip {skip} cannot be converted to the output type")
+            }
+            CyclicError::BlockWiderThanStep { stop, step } => write!(f, "stop {stop} is outside of step {step}"),
+            CyclicError::StepExceedsLength { step, len } => write!(f, "step {step} > length {len}"),
+            CyclicError::Overflow { start, end, step } => {
+                write!(f, "range {start}..={end} with step {step} overflows the output type")
+            }
+            CyclicError::ViewOutOfBounds { last, len } => write!(f, "view index {last} is outside of length {len}"),
+            CyclicError::LengthMismatch { src, dst } => {
+                write!(f, "source pattern has {src} elements, destination pattern has {dst}")
+            }
+        }
+    }
+}
+
+impl<T: Debug + Display> Error for CyclicError<T> {}
+
+/// An error returned when a pattern cannot be parsed from a string.
+///
+/// The position is the byte offset in the string where the error was found.
+/// See the `FromStr` implementation of [`StridePattern`](crate::StridePattern) for the syntax.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{ParsePatternError, ParsePatternErrorKind, StridePattern};
+///
+/// let err = "0..=2 / x".parse::<StridePattern>().unwrap_err();
+/// assert_eq!(err, ParsePatternError { position: 8, kind: ParsePatternErrorKind::ExpectedNumber });
+/// assert_eq!(err.to_string(), "expected a number at position 8");
+///
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParsePatternError {
+    /// The byte offset of the error in the string.
+    pub position: usize,
+    /// The reason of the error.
+    pub kind: ParsePatternErrorKind,
+}
+
+/// The reason of a [`ParsePatternError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParsePatternErrorKind {
+    /// A number is missing.
+    ExpectedNumber,
+    /// The number does not fit into the type.
+    InvalidNumber,
+    /// The block bounds `..`, `..=` or `:` are missing.
+    ExpectedBounds,
+    /// The step `/` or the `skip` keyword is missing.
+    ExpectedStep,
+    /// The start of the block is greater than its stop, in an exclusive block
+    /// or in a block converted between the step and the number of skipped values.
+    InvertedBounds,
+    /// The exclusive stop leaves the block empty.
+    EmptyBlock,
+    /// The step is not greater than the length of the block.
+    StepTooSmall,
+    /// The step does not fit into `usize`.
+    Overflow,
+    /// The length is given for a pattern without length.
+    UnexpectedLen,
+    /// The string continues after the pattern.
+    TrailingInput,
+}
+
+impl Display for ParsePatternErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParsePatternErrorKind::ExpectedNumber => "expected a number",
+            ParsePatternErrorKind::InvalidNumber => "invalid number",
+            ParsePatternErrorKind::ExpectedBounds => "expected `..`, `..=` or `:`",
+            ParsePatternErrorKind::ExpectedStep => "expected `/` or `skip`",
+            ParsePatternErrorKind::InvertedBounds => "start is greater than stop",
+            ParsePatternErrorKind::EmptyBlock => "empty block",
+            ParsePatternErrorKind::StepTooSmall => "step is not greater than the block",
+            ParsePatternErrorKind::Overflow => "step overflows usize",
+            ParsePatternErrorKind::UnexpectedLen => "len is only allowed in a stride pattern",
+            ParsePatternErrorKind::TrailingInput => "unexpected input",
+        })
+    }
+}
+
+impl Display for ParsePatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl Error for ParsePatternError {}
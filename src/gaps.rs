@@ -0,0 +1,102 @@
+use crate::blocks::Blocks;
+use crate::overflow::Spans;
+use num_convert::TryFromByAdd;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// An iterator over the values that a cyclic iterator skips.
+///
+/// The gaps are the values before the first block, between the blocks and after the last block,
+/// up to the limits of the type, or up to the end for [`RangeStepIdx`](crate::RangeStepIdx).
+/// They follow the order, the limits and the overflow policy of the source pattern,
+/// for a [`Wrapping`](crate::Wrapping) iterator the values between the blocks wrap
+/// as the blocks and there are no values after the last block.
+///
+/// Created by the [`RangeSkip::gaps()`](crate::RangeSkip::gaps),
+/// [`RangeStep::gaps()`](crate::RangeStep::gaps),
+/// [`Wrapping::gaps()`](crate::Wrapping::gaps)
+/// and [`RangeStepIdx::gaps()`](crate::RangeStepIdx::gaps) methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_step_idx;
+///
+/// let gaps: Vec<usize> = range_step_idx(1, 2, 7, 16).gaps().collect();
+/// assert_eq!(gaps, [0, 3, 4, 5, 6, 7, 10, 11, 12, 13, 14, 15]);
+///
+/// let gaps: Vec<_> = range_step_idx(1, 2, 7, 16).gaps().into_blocks().collect();
+/// assert_eq!(gaps, [0..=0, 3..=7, 10..=15]);
+///
+/// ```
+#[derive(Clone, Debug)]
+pub struct Gaps<T> {
+    spans: Spans,
+    marker: PhantomData<T>,
+}
+
+impl<T> Gaps<T> {
+    #[inline]
+    pub(crate) fn new(spans: Spans) -> Self {
+        Gaps {
+            spans,
+            marker: PhantomData,
+        }
+    }
+
+    /// Turns the iterator into an iterator over the gaps, one inclusive range per gap.
+    ///
+    /// See the [`Blocks`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip;
+    ///
+    /// let gaps: Vec<_> = range_skip(10_u8..15, 200).gaps().into_blocks().collect();
+    /// assert_eq!(gaps, [0..=9, 16..=215, 222..=255]);
+    ///
+    /// ```
+    #[inline]
+    pub fn into_blocks(self) -> Blocks<T> {
+        Blocks::new(self.spans)
+    }
+}
+
+impl<T> Iterator for Gaps<T>
+where
+    T: TryFromByAdd,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.spans.next().and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.spans.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Gaps<T>
+where
+    T: TryFromByAdd,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.spans.next_back().and_then(T::try_from_u128)
+    }
+}
+
+impl<T> FusedIterator for Gaps<T> where T: TryFromByAdd {}
+
+// The number of values of wider types may not fit into usize.
+macro_rules! gaps_exact_size {
+    ($($t:ty),+) => {
+        $(impl ExactSizeIterator for Gaps<$t> {})+
+    };
+}
+
+gaps_exact_size!(u8, i8, u16, i16);
@@ -6,92 +6,404 @@
     rustdoc::broken_intra_doc_links
 )]
 //! Cyclic iterators, range skip and range step.
-use num::{CheckedAdd, CheckedSub, One, Zero};
+mod algebra;
+mod blocks;
+mod chunks;
+mod copy;
+mod cursor;
+mod error;
+mod gaps;
+mod nested;
+mod overflow;
+mod parallel;
+mod parse;
+mod pattern;
+mod remove;
+mod slice;
+mod view;
+mod wrapping;
+mod write;
+
+pub use algebra::{Merged, MergedIter, PatternSet};
+pub use blocks::Blocks;
+pub use chunks::{RangeStepBlocks, RangeStepChunks, RangeStepChunksMut};
+pub use copy::{copy_range_step, swap_range_step};
+use cursor::Cursor;
+pub use error::{CyclicError, ParsePatternError, ParsePatternErrorKind};
+pub use gaps::Gaps;
+pub use nested::{range_step_nested, try_range_step_nested, Compose, NestedStride};
+pub use overflow::OverflowPolicy;
+pub use parallel::RangeStepPar;
+use overflow::{Span, Spans};
+pub use pattern::{SkipPattern, StepPattern, StridePattern};
+pub use remove::{RangeStepDrain, RangeStepRemove};
+pub use slice::{RangeStepMut, RangeStepRef, RangeStepSlice, RangeStepSliceIter};
+pub use view::{StridedIdx, StridedIter, StridedView, StridedViewMut};
+pub use wrapping::Wrapping;
+pub use write::{Exhausted, RangeStepWrite};
+use write::write_values;
+use num::{Bounded, CheckedAdd, CheckedSub, One, Zero};
 use num_convert::{TryFromByAdd, TryToByAdd};
 use std::cmp::PartialOrd;
+use std::collections::VecDeque;
 use std::fmt::{Debug, Display};
-use std::ops::{AddAssign, Range};
-use std::iter::Map;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Bound, Range, RangeBounds};
 
 /// An iterator that sequentially outputs a value in a range skipping n elements.
 ///
 /// See the [`range_skip()`] function for more information.
 #[derive(Debug, Clone)]
 pub struct RangeSkip<T> {
-    start: T,
-    end: T,
-    skip: T,
-    diff: T,
-    flag: bool,
+    span: Span,
+    marker: PhantomData<T>,
 }
 
 impl<T> RangeSkip<T>
 where
-    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Zero,
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Zero + Bounded + TryToByAdd,
     usize: TryInto<T>,
     <usize as TryInto<T>>::Error: Debug,
 {
     #[inline]
-    fn new(range: Range<T>, skip: usize) -> Self {
-        let Range { start, end } = range;
+    fn new(start: T, end: T, skip: usize) -> Self {
+        match Self::try_new_checked(start, end, skip) {
+            Ok(iter) => iter,
+            Err(CyclicError::InvertedBounds { start, end }) => panic!("start {start} > end {end}"),
+            Err(CyclicError::SkipConversion { .. }) => panic!("expected conversion to succeed"),
+            Err(_) if end.checked_sub(&start).is_none() => {
+                panic!("expected subtraction to succeed")
+            }
+            Err(_) => panic!("expected addition to succeed"),
+        }
+    }
+
+    /// Creates the iterator with the checks of [`range_skip()`],
+    /// the skip must fit into the output type and the second block must fit after the first one.
+    #[inline]
+    fn try_new_checked(start: T, end: T, skip: usize) -> Result<Self, CyclicError<T>> {
         if start > end {
-            panic!("start {start} > end {end}");
+            return Err(CyclicError::InvertedBounds { start, end });
         }
-        let skip = skip.try_into().expect("expected conversion to succeed");
-        let diff = if skip != T::zero() {
-            let diff_tmp = end
-                .checked_sub(&start)
-                .expect("expected subtraction to succeed")
-                .checked_add(&skip)
-                .expect("expected addition to succeed")
-                .checked_add(&T::one())
-                .expect("expected addition to succeed");
-            let _ = end
-                .checked_add(&diff_tmp)
-                .expect("expected addition to succeed");
-            diff_tmp
+        let skip_t: T = skip
+            .try_into()
+            .map_err(|_| CyclicError::SkipConversion { skip })?;
+        if skip_t != T::zero() {
+            end.checked_sub(&start)
+                .and_then(|diff| diff.checked_add(&skip_t))
+                .and_then(|diff| diff.checked_add(&T::one()))
+                .and_then(|diff| end.checked_add(&diff))
+                .ok_or(CyclicError::Overflow { start, end, step: skip })?;
+        }
+        Self::try_new(start, end, skip, false)
+    }
+}
+
+impl<T> RangeSkip<T>
+where
+    T: PartialOrd + Copy + TryToByAdd + Bounded,
+{
+    #[inline]
+    fn try_new(start: T, end: T, skip: usize, descending: bool) -> Result<Self, CyclicError<T>> {
+        if start > end {
+            return Err(CyclicError::InvertedBounds { start, end });
+        }
+        let overflow = CyclicError::Overflow { start, end, step: skip };
+        let (Some(low), Some(high), Some(max)) = (
+            start.try_into_u128(),
+            end.try_into_u128(),
+            T::max_value().try_into_u128(),
+        ) else {
+            return Err(overflow);
+        };
+        // The descending blocks are the ascending ones counted from the maximum of the type.
+        let (first, last) = if descending {
+            (high ^ max, low ^ max)
         } else {
-            T::zero()
+            (low, high)
         };
-        RangeSkip {
-            start,
-            end,
-            skip,
-            diff,
-            flag: true,
+        let width = last - first;
+        let cursor = if skip == 0 {
+            Cursor::new(first, width, width.saturating_add(1), 1)
+        } else {
+            let period = width
+                .checked_add(1)
+                .and_then(|width| width.checked_add(skip as u128))
+                .ok_or(overflow)?;
+            Cursor::new(first, width, period, (max - last) / period + 1)
+        };
+        Ok(RangeSkip {
+            span: Span::new(cursor, max, skip != 0, descending),
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T> RangeSkip<T> {
+    /// Sets the behavior of the iterator when the next block does not fit into the output type.
+    ///
+    /// The default is [`OverflowPolicy::Stop`], the iterator ends after the last block that fits.
+    /// A skip of zero outputs a single block under any policy.
+    /// Setting the policy moves the back of the iterator to the end given by the policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{range_skip_desc, OverflowPolicy};
+    ///
+    /// let vec: Vec<u8> = range_skip_desc(3..=5, 2).collect();
+    /// assert_eq!(vec, [5, 4, 3]);
+    ///
+    /// let vec: Vec<u8> = range_skip_desc(3..=5, 2).overflow(OverflowPolicy::Saturate).collect();
+    /// assert_eq!(vec, [5, 4, 3, 0]);
+    ///
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflow(mut self, policy: OverflowPolicy) -> Self {
+        self.span.set_policy(policy);
+        self
+    }
+
+    /// Turns the iterator into an iterator that continues the blocks modulo the range of the type.
+    ///
+    /// The overflow policy is replaced, the limits are kept.
+    /// See the [`Wrapping`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip_desc;
+    ///
+    /// let vec: Vec<u8> = range_skip_desc(3..=5, 2).wrapping().take(8).collect();
+    /// assert_eq!(vec, [5, 4, 3, 0, 255, 254, 251, 250]);
+    ///
+    /// ```
+    #[inline]
+    pub fn wrapping(self) -> Wrapping<T> {
+        Wrapping::new(self.span)
+    }
+
+    /// Turns the iterator into an iterator over its blocks, one inclusive range per block.
+    ///
+    /// See the [`Blocks`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip_desc;
+    ///
+    /// let blocks: Vec<_> = range_skip_desc(98_u8..=100, 7).blocks(3).into_blocks().collect();
+    /// assert_eq!(blocks, [98..=100, 88..=90, 78..=80]);
+    ///
+    /// ```
+    #[inline]
+    pub fn into_blocks(self) -> Blocks<T> {
+        Blocks::new(self.span)
+    }
+
+    /// Returns an iterator over the values that the pattern of the iterator skips.
+    ///
+    /// The gaps are computed from the whole pattern, not from the remaining values,
+    /// an empty range or an invalid pattern has no gaps.
+    /// See the [`Gaps`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip;
+    ///
+    /// let gaps: Vec<u8> = range_skip(0_u8..1, 250).gaps().rev().take(4).collect();
+    /// assert_eq!(gaps, [255, 254, 251, 250]);
+    ///
+    /// ```
+    #[inline]
+    pub fn gaps(&self) -> Gaps<T> {
+        Gaps::new(self.span.gaps())
+    }
+
+    /// Checks whether the pattern of the iterator outputs the value.
+    ///
+    /// The check is in constant time and covers the whole pattern, not only the remaining values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip;
+    ///
+    /// let iter = range_skip(0_u8..5, 200);
+    /// assert!(iter.contains(208));
+    /// assert!(!iter.contains(100));
+    /// assert_eq!(iter.position_of(208), Some(8));
+    ///
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: T) -> bool
+    where
+        T: TryToByAdd,
+    {
+        self.position_of(value).is_some()
+    }
+
+    /// Returns the position of the value in the pattern of the iterator, counted from zero.
+    ///
+    /// See the [`contains()`](Self::contains) method.
+    #[inline]
+    pub fn position_of(&self, value: T) -> Option<usize>
+    where
+        T: TryToByAdd,
+    {
+        let idx = self.span.position_of(value.try_into_u128()?)?;
+        usize::try_from(idx).ok()
+    }
+
+    /// Returns the value at the position in the pattern of the iterator, counted from zero.
+    ///
+    /// The inverse of the [`position_of()`](Self::position_of) method,
+    /// the same as `nth(index)` on the fresh iterator, in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip_desc;
+    ///
+    /// let iter = range_skip_desc(98_u8..=100, 7);
+    /// assert_eq!(iter.value_at(4), Some(89));
+    /// assert_eq!(iter.position_of(89), Some(4));
+    /// assert_eq!(iter.value_at(30), None);
+    ///
+    /// ```
+    #[inline]
+    pub fn value_at(&self, index: usize) -> Option<T>
+    where
+        T: TryFromByAdd,
+    {
+        self.span.offset_at(index as u128).and_then(T::try_from_u128)
+    }
+
+    /// Limits the iterator to the first `n` blocks.
+    ///
+    /// The limits and the overflow policy combine, the iterator ends at the first end reached,
+    /// the size hint stays exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip;
+    ///
+    /// let iter = range_skip(0_u8..1, 100).blocks(2);
+    /// assert_eq!(iter.len(), 4);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 102, 103]);
+    ///
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn blocks(mut self, n: usize) -> Self {
+        self.span.limit_blocks(n as u128);
+        self
+    }
+
+    /// Limits the iterator to the whole blocks that do not go past the `limit` value,
+    /// above it when counting up, below it when counting down.
+    /// The limit at the end of the type keeps the block cut by [`OverflowPolicy::Saturate`].
+    ///
+    /// See the [`RangeSkip::until_partial()`] method to cut the last block at the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_skip_desc;
+    ///
+    /// let vec: Vec<u8> = range_skip_desc(98..=100, 7).until(75).collect();
+    /// assert_eq!(vec, [100, 99, 98, 90, 89, 88, 80, 79, 78]);
+    ///
+    /// let vec: Vec<u8> = range_skip_desc(98..=100, 7).until_partial(79).collect();
+    /// assert_eq!(vec, [100, 99, 98, 90, 89, 88, 80, 79]);
+    ///
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn until(mut self, limit: T) -> Self
+    where
+        T: TryToByAdd,
+    {
+        if let Some(limit) = limit.try_into_u128() {
+            self.span.limit_offset(limit, false);
+        }
+        self
+    }
+
+    /// Limits the iterator to the values that do not go past the `limit` value,
+    /// the last block is cut at the limit.
+    ///
+    /// See the [`RangeSkip::until()`] method.
+    #[inline]
+    #[must_use]
+    pub fn until_partial(mut self, limit: T) -> Self
+    where
+        T: TryToByAdd,
+    {
+        if let Some(limit) = limit.try_into_u128() {
+            self.span.limit_offset(limit, true);
         }
+        self
     }
 }
 
 impl<T> Iterator for RangeSkip<T>
 where
-    T: PartialOrd + Copy + AddAssign + CheckedAdd + One + Zero,
+    T: TryFromByAdd,
 {
     type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<T> {
-        if self.flag {
-            self.flag = false;
-            return Some(self.start);
-        }
-        if self.start == self.end {
-            if self.skip == Zero::zero() {
-                return None;
-            }
-            if let Some(end) = self.end.checked_add(&self.diff) {
-                self.end = end;
-                self.start += self.skip + T::one();
-                return Some(self.start);
-            } else {
-                return None;
-            };
-        }
-        self.start += T::one();
-        Some(self.start)
+        self.span.next().and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.span.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.span.nth(n).and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<T> {
+        self.next_back()
     }
 }
 
+impl<T> DoubleEndedIterator for RangeSkip<T>
+where
+    T: TryFromByAdd,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.span.next_back().and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.span.nth_back(n).and_then(T::try_from_u128)
+    }
+}
+
+impl<T> FusedIterator for RangeSkip<T> where T: TryFromByAdd {}
+
+// The number of values of wider types may not fit into usize, under the saturation it is the whole type.
+macro_rules! range_step_exact_size {
+    ($iter:ident: $($t:ty),+) => {
+        $(impl ExactSizeIterator for $iter<$t> {})+
+    };
+}
+
+range_step_exact_size!(RangeSkip: u8, i8, u16, i16);
+
 /// Creates an iterator that sequentially outputs a value in the range
 /// with a skipping of n elements.
 ///
@@ -104,6 +416,9 @@ where
 /// If the start value is greater than the end value, panic.
 /// Panic if value skip conversion to output type error.
 ///
+/// The end of the range is inclusive, unlike the std ranges,
+/// see the [`range_skip_bounds()`] function for the std semantics.
+///
 /// # Examples
 ///
 /// ```rust
@@ -112,15 +427,254 @@ where
 /// let vec: Vec<u8> = range_skip(0..5, 200).collect();
 /// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 206, 207, 208, 209, 210, 211]);
 ///
+/// let vec: Vec<u8> = range_skip(0..5, 200).rev().take(3).collect();
+/// assert_eq!(vec, [211, 210, 209]);
+///
 /// ```
 #[inline]
 pub fn range_skip<T>(range: Range<T>, skip: usize) -> RangeSkip<T>
 where
-    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Zero,
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Zero + Bounded + TryToByAdd,
+    usize: TryInto<T>,
+    <usize as TryInto<T>>::Error: Debug,
+{
+    RangeSkip::new(range.start, range.end, skip)
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a skipping of n elements, or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_skip()`] function.
+///
+/// # Errors
+///
+/// [`CyclicError::InvertedBounds`] if the start value is greater than the end value,
+/// [`CyclicError::SkipConversion`] if skip cannot be converted to the output type,
+/// [`CyclicError::Overflow`] if the second block does not fit into the output type.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_skip, CyclicError};
+///
+/// let vec: Vec<u8> = try_range_skip(0..5, 200).unwrap().collect();
+/// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 206, 207, 208, 209, 210, 211]);
+///
+/// let err = try_range_skip(0_u8..127, 1).unwrap_err();
+/// assert_eq!(err, CyclicError::Overflow { start: 0, end: 127, step: 1 });
+///
+/// ```
+#[inline]
+pub fn try_range_skip<T>(range: Range<T>, skip: usize) -> Result<RangeSkip<T>, CyclicError<T>>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Zero + Bounded + TryToByAdd,
     usize: TryInto<T>,
     <usize as TryInto<T>>::Error: Debug,
 {
-    RangeSkip::new(range, skip)
+    RangeSkip::try_new_checked(range.start, range.end, skip)
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a skipping of n elements, where the range has any form of [`RangeBounds`].
+///
+/// Unlike [`range_skip()`], the bounds follow the std semantics:
+/// `0..6` and `0..=5` are the same block, `5..` ends at the maximum value of the type,
+/// `..=5` starts at the minimum value of the type.
+/// An empty range gives an empty iterator.
+/// The iterator stops before the block that does not fit into the output type,
+/// even if it is the second one, see [`RangeSkip::overflow()`] for the other behaviors.
+///
+/// If the start value is greater than the end value, panic.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_skip_bounds;
+///
+/// let vec: Vec<u8> = range_skip_bounds(0..=5, 200).collect();
+/// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 206, 207, 208, 209, 210, 211]);
+///
+/// let vec: Vec<u8> = range_skip_bounds(0..2, 200).collect();
+/// assert_eq!(vec, [0, 1, 202, 203]);
+///
+/// let vec: Vec<i8> = range_skip_bounds(..=-127, 0).collect();
+/// assert_eq!(vec, [-128, -127]);
+///
+/// ```
+#[inline]
+pub fn range_skip_bounds<T, R>(range: R, skip: usize) -> RangeSkip<T>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Bounded + TryToByAdd,
+    R: RangeBounds<T>,
+{
+    try_range_skip_bounds(range, skip).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a skipping of n elements, where the range has any form of [`RangeBounds`],
+/// or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_skip_bounds()`] function.
+///
+/// # Errors
+///
+/// [`CyclicError::InvertedBounds`] if the start value is greater than the end value,
+/// [`CyclicError::Overflow`] if the period of the blocks does not fit into `u128`.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_skip_bounds, CyclicError};
+///
+/// let vec: Vec<u8> = try_range_skip_bounds(250.., 0).unwrap().collect();
+/// assert_eq!(vec, [250, 251, 252, 253, 254, 255]);
+///
+/// let vec: Vec<u8> = try_range_skip_bounds(0..=127, 1).unwrap().collect();
+/// assert_eq!(vec, (0..=127).collect::<Vec<_>>());
+///
+/// let err = try_range_skip_bounds(10_u8..=0, 1).unwrap_err();
+/// assert_eq!(err, CyclicError::InvertedBounds { start: 10, end: 0 });
+///
+/// ```
+#[inline]
+pub fn try_range_skip_bounds<T, R>(range: R, skip: usize) -> Result<RangeSkip<T>, CyclicError<T>>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Bounded + TryToByAdd,
+    R: RangeBounds<T>,
+{
+    match inclusive_bounds(&range)? {
+        Some((start, end)) => RangeSkip::try_new(start, end, skip, false),
+        None => Ok(RangeSkip::empty(false)),
+    }
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a skipping of n elements, counting down.
+///
+/// The blocks start at the end of the range and go down,
+/// the iterator stops before the block that does not fit above the minimum value of the type.
+/// The range has any form of [`RangeBounds`], see the [`range_skip_bounds()`] function.
+///
+/// If the start value is greater than the end value, panic.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_skip_desc;
+///
+/// let vec: Vec<u8> = range_skip_desc(98..=100, 7).take(9).collect();
+/// assert_eq!(vec, [100, 99, 98, 90, 89, 88, 80, 79, 78]);
+///
+/// let vec: Vec<i8> = range_skip_desc(-124..=-123, 1).collect();
+/// assert_eq!(vec, [-123, -124, -126, -127]);
+///
+/// ```
+#[inline]
+pub fn range_skip_desc<T, R>(range: R, skip: usize) -> RangeSkip<T>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Bounded + TryToByAdd,
+    R: RangeBounds<T>,
+{
+    try_range_skip_desc(range, skip).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a skipping of n elements, counting down, or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_skip_desc()`] function.
+///
+/// # Errors
+///
+/// The same as [`try_range_skip_bounds()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_skip_desc, CyclicError};
+///
+/// let vec: Vec<u8> = try_range_skip_desc(4..=5, 1).unwrap().collect();
+/// assert_eq!(vec, [5, 4, 2, 1]);
+///
+/// let vec: Vec<u8> = try_range_skip_desc(1..=2, 1).unwrap().collect();
+/// assert_eq!(vec, [2, 1]);
+///
+/// let err = try_range_skip_desc(2_u8..1, 1).unwrap_err();
+/// assert_eq!(err, CyclicError::InvertedBounds { start: 2, end: 1 });
+///
+/// ```
+#[inline]
+pub fn try_range_skip_desc<T, R>(range: R, skip: usize) -> Result<RangeSkip<T>, CyclicError<T>>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Bounded + TryToByAdd,
+    R: RangeBounds<T>,
+{
+    match inclusive_bounds(&range)? {
+        Some((start, end)) => RangeSkip::try_new(start, end, skip, true),
+        None => Ok(RangeSkip::empty(true)),
+    }
+}
+
+impl<T> RangeSkip<T>
+where
+    T: TryToByAdd + Bounded,
+{
+    /// Creates an iterator without values that keeps the limits of the type.
+    #[inline]
+    fn empty(descending: bool) -> Self {
+        RangeSkip {
+            span: Span::empty_of(type_max::<T>(), descending),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Returns the maximum value of the type as an offset.
+#[inline]
+fn type_max<T>() -> u128
+where
+    T: TryToByAdd + Bounded,
+{
+    T::max_value().try_into_u128().unwrap_or(u128::MAX)
+}
+
+/// Returns the values of the range bounds, the type limits for the unbounded ones.
+#[inline]
+fn bound_values<T, R>(range: &R) -> (T, T)
+where
+    T: Copy + Bounded,
+    R: RangeBounds<T>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) | Bound::Excluded(&start) => start,
+        Bound::Unbounded => T::min_value(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) | Bound::Excluded(&end) => end,
+        Bound::Unbounded => T::max_value(),
+    };
+    (start, end)
+}
+
+/// Converts the range bounds to the inclusive start and end, `None` if the range is empty.
+#[inline]
+fn inclusive_bounds<T, R>(range: &R) -> Result<Option<(T, T)>, CyclicError<T>>
+where
+    T: PartialOrd + Copy + Bounded + CheckedAdd + CheckedSub + One,
+    R: RangeBounds<T>,
+{
+    let (start, end) = bound_values(range);
+    if start > end {
+        return Err(CyclicError::InvertedBounds { start, end });
+    }
+    let start = match range.start_bound() {
+        Bound::Excluded(start) => start.checked_add(&T::one()),
+        _ => Some(start),
+    };
+    let end = match range.end_bound() {
+        Bound::Excluded(end) => end.checked_sub(&T::one()),
+        _ => Some(end),
+    };
+    Ok(start.zip(end).filter(|(start, end)| start <= end))
 }
 
 /// An iterator that sequentially outputs a value in a range in increments of n elements.
@@ -128,64 +682,56 @@ where
 /// See the [`range_step()`] function for more information.
 #[derive(Copy, Clone, Debug)]
 pub struct RangeStep<T> {
-    start: T,
-    stop: T,
-    start_usize: usize,
-    stop_usize: usize,
-    step: usize,
-    step_next: usize,
-    flag: bool,
+    span: Span,
+    marker: PhantomData<T>,
 }
 
 impl<T> Iterator for RangeStep<T>
 where
-    T: Clone
-        + Copy
-        + Clone
-        + Debug
-        + TryToByAdd
-        + TryFromByAdd
-        + One
-        + AddAssign
-        + CheckedAdd
-        + PartialEq,
+    T: TryFromByAdd,
 {
     type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.flag {
-            if self.step == 0 {
-                return None;
-            }
-            self.flag = false;
-            return Some(self.start);
-        }
+        self.span.next().and_then(T::try_from_u128)
+    }
 
-        if self.start == self.stop {
-            if let Some(step_next) = self.step_next.checked_add(self.step) {
-                self.step_next = step_next;
-                match <T as TryFromByAdd>::try_from_usize(self.step_next) {
-                    Some(_) => {
-                        self.start_usize += self.step;
-                        self.stop_usize += self.step;
-                        self.start = <T as TryFromByAdd>::try_from_usize(self.start_usize).unwrap();
-                        self.stop = <T as TryFromByAdd>::try_from_usize(self.stop_usize).unwrap();
-                        return Some(self.start);
-                    }
-                    None => {
-                        return None;
-                    }
-                }
-            } else {
-                return None;
-            }
-        }
-        self.start += T::one();
-        Some(self.start)
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.span.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.span.nth(n).and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeStep<T>
+where
+    T: TryFromByAdd,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.span.next_back().and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.span.nth_back(n).and_then(T::try_from_u128)
     }
 }
 
+range_step_exact_size!(RangeStep: u8, i8, u16, i16);
+
+impl<T> FusedIterator for RangeStep<T> where T: TryFromByAdd {}
+
 /// Creates an iterator that sequentially outputs a value in the range
 /// with a step of n elements.
 ///
@@ -207,24 +753,462 @@ where
 #[inline]
 pub fn range_step<T>(start: T, stop: T, step: usize) -> RangeStep<T>
 where
-    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd,
+    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd + Bounded,
+{
+    let start_usize = start.try_into_usize().unwrap();
+    let stop_usize = stop.try_into_usize().unwrap();
+    let _ = <T as TryFromByAdd>::try_from_usize(step - 1).unwrap();
+
+    RangeStep::new(start_usize, stop_usize, step, false)
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a step of n elements, or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_step()`] function.
+///
+/// # Errors
+///
+/// [`CyclicError::InvertedBounds`] if the start value is greater than the stop value,
+/// [`CyclicError::BlockWiderThanStep`] if the stop value is not less than the step,
+/// [`CyclicError::Overflow`] if the values or the step do not fit into the output type.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step, CyclicError};
+///
+/// let vec: Vec<u8> = try_range_step(0, 5, 20).unwrap().take(12).collect();
+/// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 20, 21, 22, 23, 24, 25]);
+///
+/// let err = try_range_step(0_u8, 5, 0).unwrap_err();
+/// assert_eq!(err, CyclicError::BlockWiderThanStep { stop: 5, step: 0 });
+///
+/// ```
+#[inline]
+pub fn try_range_step<T>(start: T, stop: T, step: usize) -> Result<RangeStep<T>, CyclicError<T>>
+where
+    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd + Bounded,
+{
+    let overflow = CyclicError::Overflow { start, end: stop, step };
+    let start_usize = start.try_into_usize().ok_or(overflow)?;
+    let stop_usize = stop.try_into_usize().ok_or(overflow)?;
+    if start_usize > stop_usize {
+        return Err(CyclicError::InvertedBounds { start, end: stop });
+    }
+    if stop_usize >= step {
+        return Err(CyclicError::BlockWiderThanStep { stop, step });
+    }
+    <T as TryFromByAdd>::try_from_usize(step - 1).ok_or(overflow)?;
+
+    Ok(RangeStep::new(start_usize, stop_usize, step, false))
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a step of n elements, where the range has any form of [`RangeBounds`].
+///
+/// The bounds follow the std semantics, see [`range_skip_bounds()`].
+/// An empty range or an invalid pattern gives an empty iterator,
+/// see the [`try_range_step_bounds()`] function for the rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_step_bounds;
+///
+/// let vec: Vec<u8> = range_step_bounds(0..6, 20).take(12).collect();
+/// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 20, 21, 22, 23, 24, 25]);
+///
+/// let vec: Vec<i8> = range_step_bounds(..=-127, 128).collect();
+/// assert_eq!(vec, [-128, -127]);
+///
+/// ```
+#[inline]
+pub fn range_step_bounds<T, R>(range: R, step: usize) -> RangeStep<T>
+where
+    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd + Bounded + PartialOrd + CheckedAdd + CheckedSub + One,
+    R: RangeBounds<T>,
+{
+    try_range_step_bounds(range, step).unwrap_or_else(|_| RangeStep::empty(false))
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a step of n elements, where the range has any form of [`RangeBounds`],
+/// or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_step_bounds()`] function.
+/// An empty range gives an empty iterator.
+///
+/// # Errors
+///
+/// The same as [`try_range_step()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step_bounds, CyclicError};
+///
+/// let vec: Vec<u8> = try_range_step_bounds(1..=2, 100).unwrap().collect();
+/// assert_eq!(vec, [1, 2, 101, 102]);
+///
+/// let err = try_range_step_bounds(0_u8..=5, 5).unwrap_err();
+/// assert_eq!(err, CyclicError::BlockWiderThanStep { stop: 5, step: 5 });
+///
+/// ```
+#[inline]
+pub fn try_range_step_bounds<T, R>(range: R, step: usize) -> Result<RangeStep<T>, CyclicError<T>>
+where
+    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd + Bounded + PartialOrd + CheckedAdd + CheckedSub + One,
+    R: RangeBounds<T>,
+{
+    match inclusive_bounds(&range)? {
+        Some((start, stop)) => try_range_step(start, stop, step),
+        None => Ok(RangeStep::empty(false)),
+    }
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a step of n elements, counting down.
+///
+/// The mirror of [`range_step()`]: the steps are counted from the maximum value of the type,
+/// the block starts at the end of the range and the iterator goes down to the minimum value of the type.
+/// The range has any form of [`RangeBounds`], an empty or invalid range gives an empty iterator.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_step_desc;
+///
+/// let vec: Vec<u8> = range_step_desc(250..=255, 20).take(12).collect();
+/// assert_eq!(vec, [255, 254, 253, 252, 251, 250, 235, 234, 233, 232, 231, 230]);
+///
+/// let vec: Vec<i8> = range_step_desc(126..=127, 127).collect();
+/// assert_eq!(vec, [127, 126, 0, -1]);
+///
+/// ```
+#[inline]
+pub fn range_step_desc<T, R>(range: R, step: usize) -> RangeStep<T>
+where
+    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd + Bounded + PartialOrd + CheckedAdd + CheckedSub + One,
+    R: RangeBounds<T>,
+{
+    match inclusive_bounds(&range) {
+        Ok(Some((start, stop))) => {
+            let start_usize = mirrored_usize(stop).unwrap();
+            let stop_usize = mirrored_usize(start).unwrap();
+            let _ = <T as TryFromByAdd>::try_from_usize(step.saturating_sub(1)).unwrap();
+
+            RangeStep::new(start_usize, stop_usize, step, true)
+        }
+        _ => RangeStep::empty(true),
+    }
+}
+
+/// Creates an iterator that sequentially outputs a value in the range
+/// with a step of n elements, counting down, or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_step_desc()`] function.
+/// An empty range gives an empty iterator.
+///
+/// # Errors
+///
+/// [`CyclicError::InvertedBounds`] if the start value is greater than the end value,
+/// [`CyclicError::BlockWiderThanStep`] if the start of the range is not in the first step below the maximum,
+/// [`CyclicError::Overflow`] if the values or the step do not fit into the output type.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step_desc, CyclicError};
+///
+/// let vec: Vec<u8> = try_range_step_desc(254..=255, 100).unwrap().collect();
+/// assert_eq!(vec, [255, 254, 155, 154]);
+///
+/// let err = try_range_step_desc(200_u8..=255, 20).unwrap_err();
+/// assert_eq!(err, CyclicError::BlockWiderThanStep { stop: 200, step: 20 });
+///
+/// ```
+#[inline]
+pub fn try_range_step_desc<T, R>(range: R, step: usize) -> Result<RangeStep<T>, CyclicError<T>>
+where
+    T: Clone + Copy + Debug + TryToByAdd + TryFromByAdd + Bounded + PartialOrd + CheckedAdd + CheckedSub + One,
+    R: RangeBounds<T>,
+{
+    let Some((start, stop)) = inclusive_bounds(&range)? else {
+        return Ok(RangeStep::empty(true));
+    };
+    let overflow = CyclicError::Overflow { start, end: stop, step };
+    let start_usize = mirrored_usize(stop).ok_or(overflow)?;
+    let stop_usize = mirrored_usize(start).ok_or(overflow)?;
+    if stop_usize >= step {
+        return Err(CyclicError::BlockWiderThanStep { stop: start, step });
+    }
+    <T as TryFromByAdd>::try_from_usize(step - 1).ok_or(overflow)?;
+
+    Ok(RangeStep::new(start_usize, stop_usize, step, true))
+}
+
+/// Converts the value to the offset from the maximum value of the type.
+#[inline]
+fn mirrored_usize<T>(val: T) -> Option<usize>
+where
+    T: TryToByAdd + Bounded,
+{
+    let max = T::max_value().try_into_u128()?;
+    usize::try_from(val.try_into_u128()? ^ max).ok()
+}
+
+impl<T> RangeStep<T>
+where
+    T: TryToByAdd + Bounded,
+{
+    /// Creates an iterator without values that keeps the limits of the type.
+    #[inline]
+    fn empty(descending: bool) -> Self {
+        RangeStep {
+            span: Span::empty_of(type_max::<T>(), descending),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> RangeStep<T> {
+
+    /// Sets the behavior of the iterator when the next step does not fit into the output type.
+    ///
+    /// The default is [`OverflowPolicy::Stop`],
+    /// the iterator ends after the last block whose whole step fits into the type.
+    /// Setting the policy moves the back of the iterator to the end given by the policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{range_step, OverflowPolicy};
+    ///
+    /// let vec: Vec<u8> = range_step(0, 1, 100).collect();
+    /// assert_eq!(vec, [0, 1, 100, 101]);
+    ///
+    /// let vec: Vec<u8> = range_step(0, 1, 100).overflow(OverflowPolicy::Saturate).collect();
+    /// assert_eq!(vec, [0, 1, 100, 101, 200, 201]);
+    ///
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflow(mut self, policy: OverflowPolicy) -> Self {
+        self.span.set_policy(policy);
+        self
+    }
+
+    /// Turns the iterator into an iterator that continues the steps modulo the range of the type.
+    ///
+    /// The overflow policy is replaced, the limits are kept.
+    /// See the [`Wrapping`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step;
+    ///
+    /// let vec: Vec<u8> = range_step(0, 1, 100).wrapping().take(8).collect();
+    /// assert_eq!(vec, [0, 1, 100, 101, 200, 201, 44, 45]);
+    ///
+    /// ```
+    #[inline]
+    pub fn wrapping(self) -> Wrapping<T> {
+        Wrapping::new(self.span)
+    }
+
+    /// Turns the iterator into an iterator over its blocks, one inclusive range per block.
+    ///
+    /// See the [`Blocks`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{range_step, OverflowPolicy};
+    ///
+    /// let mut iter = range_step(0_u8, 9, 83).overflow(OverflowPolicy::Saturate);
+    /// iter.next();
+    /// let blocks: Vec<_> = iter.into_blocks().collect();
+    /// assert_eq!(blocks, [1..=9, 83..=92, 166..=175, 249..=255]);
+    ///
+    /// ```
+    #[inline]
+    pub fn into_blocks(self) -> Blocks<T> {
+        Blocks::new(self.span)
+    }
+
+    /// Returns an iterator over the values that the pattern of the iterator skips.
+    ///
+    /// The gaps are computed from the whole pattern, not from the remaining values,
+    /// an empty range or an invalid pattern has no gaps.
+    /// See the [`Gaps`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step_desc;
+    ///
+    /// let gaps: Vec<_> = range_step_desc(100_u8..=255, 200).gaps().into_blocks().collect();
+    /// assert_eq!(gaps, [0..=99]);
+    ///
+    /// ```
+    #[inline]
+    pub fn gaps(&self) -> Gaps<T> {
+        Gaps::new(self.span.gaps())
+    }
+
+    /// Checks whether the pattern of the iterator outputs the value.
+    ///
+    /// The check is in constant time and covers the whole pattern, not only the remaining values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{range_step, OverflowPolicy};
+    ///
+    /// let iter = range_step(0_u8, 1, 100);
+    /// assert!(!iter.contains(200));
+    /// assert!(iter.overflow(OverflowPolicy::Saturate).contains(200));
+    ///
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: T) -> bool
+    where
+        T: TryToByAdd,
+    {
+        self.position_of(value).is_some()
+    }
+
+    /// Returns the position of the value in the pattern of the iterator, counted from zero.
+    ///
+    /// See the [`contains()`](Self::contains) method.
+    #[inline]
+    pub fn position_of(&self, value: T) -> Option<usize>
+    where
+        T: TryToByAdd,
+    {
+        let idx = self.span.position_of(value.try_into_u128()?)?;
+        usize::try_from(idx).ok()
+    }
+
+    /// Returns the value at the position in the pattern of the iterator, counted from zero.
+    ///
+    /// The inverse of the [`position_of()`](Self::position_of) method,
+    /// the same as `nth(index)` on the fresh iterator, in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step;
+    ///
+    /// let iter = range_step(-128_i8, -127, 100);
+    /// assert_eq!(iter.value_at(3), Some(-27));
+    /// assert_eq!(iter.value_at(4), None);
+    ///
+    /// ```
+    #[inline]
+    pub fn value_at(&self, index: usize) -> Option<T>
+    where
+        T: TryFromByAdd,
+    {
+        self.span.offset_at(index as u128).and_then(T::try_from_u128)
+    }
+
+    /// Limits the iterator to the first `n` blocks.
+    ///
+    /// The limits and the overflow policy combine, the iterator ends at the first end reached,
+    /// the size hint stays exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step;
+    ///
+    /// let iter = range_step(0_u8, 5, 20).blocks(2);
+    /// assert_eq!(iter.len(), 12);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 20, 21, 22, 23, 24, 25]);
+    ///
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn blocks(mut self, n: usize) -> Self {
+        self.span.limit_blocks(n as u128);
+        self
+    }
+
+    /// Limits the iterator to the whole blocks that do not go past the `limit` value,
+    /// above it when counting up, below it when counting down.
+    /// The limit at the end of the type keeps the block cut by [`OverflowPolicy::Saturate`].
+    ///
+    /// See the [`RangeStep::until_partial()`] method to cut the last block at the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step;
+    ///
+    /// let vec: Vec<u8> = range_step(0, 1, 20).until(42).collect();
+    /// assert_eq!(vec, [0, 1, 20, 21, 40, 41]);
+    ///
+    /// let vec: Vec<u8> = range_step(0, 5, 20).until(42).collect();
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 20, 21, 22, 23, 24, 25]);
+    ///
+    /// let vec: Vec<u8> = range_step(0, 5, 20).until_partial(42).skip(12).collect();
+    /// assert_eq!(vec, [40, 41, 42]);
+    ///
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn until(mut self, limit: T) -> Self
+    where
+        T: TryToByAdd,
+    {
+        if let Some(limit) = limit.try_into_u128() {
+            self.span.limit_offset(limit, false);
+        }
+        self
+    }
+
+    /// Limits the iterator to the values that do not go past the `limit` value,
+    /// the last block is cut at the limit.
+    ///
+    /// See the [`RangeStep::until()`] method.
+    #[inline]
+    #[must_use]
+    pub fn until_partial(mut self, limit: T) -> Self
+    where
+        T: TryToByAdd,
+    {
+        if let Some(limit) = limit.try_into_u128() {
+            self.span.limit_offset(limit, true);
+        }
+        self
+    }
+}
+
+impl<T> RangeStep<T>
+where
+    T: TryToByAdd + Bounded,
 {
-    let start_usize = start.try_into_usize().unwrap();
-    let stop_usize = stop.try_into_usize().unwrap();
-    let _ = <T as TryFromByAdd>::try_from_usize(step - 1).unwrap();
-
-    RangeStep {
-        start,
-        stop,
-        start_usize,
-        stop_usize,
-        step: if start_usize > stop_usize || stop_usize >= step {
-            0
-        } else {
-            step
-        },
-        step_next: step,
-        flag: true,
+    #[inline]
+    fn new(start_usize: usize, stop_usize: usize, step: usize, descending: bool) -> Self {
+        if start_usize > stop_usize || stop_usize >= step {
+            return Self::empty(descending);
+        }
+        // The next block is started only if the whole step fits into the type.
+        let max = T::max_value().try_into_usize().unwrap_or(usize::MAX);
+        let blocks = (max / step).max(1);
+        let cursor = Cursor::new(
+            start_usize as u128,
+            (stop_usize - start_usize) as u128,
+            step as u128,
+            blocks as u128,
+        );
+        RangeStep {
+            span: Span::new(cursor, type_max::<T>(), true, descending),
+            marker: PhantomData,
+        }
     }
 }
 
@@ -233,13 +1217,8 @@ where
 /// See the [`range_step_idx()`] function for more information.
 #[derive(Clone, Copy, Debug)]
 pub struct RangeStepIdx {
-    start: usize,
-    start_next: usize,
-    stop: usize,
-    step: usize,
-    step_next: usize,
+    cursor: Cursor<usize>,
     end: usize,
-    once_flag: bool,
 }
 
 impl Iterator for RangeStepIdx {
@@ -247,35 +1226,170 @@ impl Iterator for RangeStepIdx {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.once_flag {
-            if self.step == 0 {
-                return None;
-            }
-            self.once_flag = false;
-            return Some(self.start);
+        self.cursor.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cursor.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.nth(n)
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for RangeStepIdx {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.nth_back(n)
+    }
+}
+
+impl RangeStepIdx {
+    /// Turns the iterator into an iterator over its blocks, one inclusive range per block.
+    ///
+    /// See the [`Blocks`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step_idx;
+    ///
+    /// let blocks: Vec<_> = range_step_idx(0, 2, 7, 22).into_blocks().rev().collect();
+    /// assert_eq!(blocks, [14..=16, 7..=9, 0..=2]);
+    ///
+    /// ```
+    #[inline]
+    pub fn into_blocks(self) -> Blocks<usize> {
+        Blocks::new(Span::new(self.cursor.widen(), usize::MAX as u128, false, false))
+    }
+
+    /// Returns an iterator over the indices below the end that the pattern of the iterator skips.
+    ///
+    /// The gaps are computed from the whole pattern, not from the remaining indices,
+    /// an invalid pattern has no gaps.
+    /// See the [`Gaps`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step_idx;
+    ///
+    /// let data = b"aabbbaabbbaa";
+    /// let other: Vec<u8> = range_step_idx(0, 1, 5, data.len()).gaps().map(|idx| data[idx]).collect();
+    /// assert_eq!(other, b"bbbbbbaa");
+    ///
+    /// ```
+    #[inline]
+    pub fn gaps(&self) -> Gaps<usize> {
+        if self.end == 0 {
+            return Gaps::new(Spans::empty());
         }
+        let cursor = self.cursor.widen();
+        Gaps::new(Spans::new(
+            cursor.gaps(self.last_index().map(|last| last as u128), (self.end - 1) as u128),
+            usize::MAX as u128,
+            false,
+        ))
+    }
 
-        if self.start == self.stop {
-            if let Some(next_step) = self.step_next.checked_add(self.step) {
-                self.step_next = next_step;
-                if self.step_next > self.end {
-                    return None;
-                }
-            } else {
-                return None;
-            }
+    /// Checks whether the pattern of the iterator outputs the index.
+    ///
+    /// The check is in constant time and covers the whole pattern, not only the remaining indices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step_idx;
+    ///
+    /// let iter = range_step_idx(0, 2, 7, 22);
+    /// assert!(iter.contains(15));
+    /// assert!(!iter.contains(12));
+    /// assert!(!iter.contains(21));
+    ///
+    /// ```
+    #[inline]
+    pub fn contains(&self, index: usize) -> bool {
+        self.position_of(index).is_some()
+    }
+
+    /// Returns the position of the index in the pattern of the iterator, counted from zero.
+    ///
+    /// See the [`contains()`](Self::contains) method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step_idx;
+    ///
+    /// let iter = range_step_idx(0, 2, 7, 22);
+    /// assert_eq!(iter.position_of(15), Some(7));
+    /// assert_eq!(iter.value_at(7), Some(15));
+    ///
+    /// ```
+    #[inline]
+    pub fn position_of(&self, index: usize) -> Option<usize> {
+        self.cursor.index_of(index, self.last_index()?)
+    }
+
+    /// Returns the index at the position in the pattern of the iterator, counted from zero.
+    ///
+    /// The inverse of the [`position_of()`](Self::position_of) method,
+    /// the same as `nth(position)` on the fresh iterator, in constant time.
+    #[inline]
+    pub fn value_at(&self, position: usize) -> Option<usize> {
+        self.cursor.offset_at(position, self.last_index()?)
+    }
+
+    /// Returns the position of the last index of the whole pattern.
+    #[inline]
+    fn last_index(&self) -> Option<usize> {
+        if self.end == 0 {
+            return None;
+        }
+        self.cursor.block_last_index(self.end / self.cursor.period())
+    }
+
+    #[inline]
+    fn new(start: usize, stop: usize, step: usize, end: usize) -> Self {
+        let cursor = step_cursor(start, stop, step, end);
+        RangeStepIdx {
+            end: if cursor.last_index().is_some() { end } else { 0 },
+            cursor,
+        }
+    }
 
-            self.start_next += self.step;
-            self.start = self.start_next;
-            self.stop += self.step;
-            return Some(self.start);
+    #[inline]
+    fn empty() -> Self {
+        RangeStepIdx {
+            cursor: Cursor::empty(),
+            end: 0,
         }
+    }
 
-        self.start += 1;
-        Some(self.start)
+    /// Returns the first and the last index of the next run of consecutive indices.
+    #[inline]
+    pub(crate) fn next_run(&mut self) -> Option<(usize, usize)> {
+        self.cursor.next_run()
     }
 }
 
+impl ExactSizeIterator for RangeStepIdx {}
+
+impl FusedIterator for RangeStepIdx {}
+
 /// Creates an iterator that sequentially outputs a value in the range with a step of n elements of type usize.
 ///
 /// Range,
@@ -296,25 +1410,124 @@ impl Iterator for RangeStepIdx {
 /// assert_eq!(vec, [10, 11, 12, 3, 4, 5, 6, 17, 18, 19, 10, 11, 12, 13, 24, 25, 26, 17, 18, 19, 20, 21]);
 ///
 /// ```
-
-/// Creates an iterator of type usize.
 #[inline]
 pub fn range_step_idx(start: usize, stop: usize, step: usize, end: usize) -> RangeStepIdx {
-    RangeStepIdx {
-        start,
-        start_next: start,
-        stop,
-        step: if start > stop || stop >= step || step > end {
-            0
-        } else {
-            step
-        },
-        step_next: step,
-        end,
-        once_flag: true,
+    RangeStepIdx::new(start, stop, step, end)
+}
+
+/// Cursor over the whole blocks of (start, stop, step) that fit into the length.
+#[inline]
+fn step_cursor(start: usize, stop: usize, step: usize, end: usize) -> Cursor<usize> {
+    if start > stop || stop >= step || step > end {
+        Cursor::empty()
+    } else {
+        Cursor::new(start, stop - start, step, end / step)
+    }
+}
+
+/// Creates an iterator that sequentially outputs a value in the range with a step of n elements of type usize,
+/// or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_step_idx()`] function, which returns an empty iterator instead.
+///
+/// # Errors
+///
+/// [`CyclicError::InvertedBounds`] if the start value is greater than the stop value,
+/// [`CyclicError::BlockWiderThanStep`] if the stop value is not less than the step,
+/// [`CyclicError::StepExceedsLength`] if the step is greater than the iterator length.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step_idx, CyclicError};
+///
+/// let idx: Vec<usize> = try_range_step_idx(0, 1, 4, 12).unwrap().collect();
+/// assert_eq!(idx, [0, 1, 4, 5, 8, 9]);
+///
+/// let err = try_range_step_idx(0, 2, 7, 5).unwrap_err();
+/// assert_eq!(err, CyclicError::StepExceedsLength { step: 7, len: 5 });
+///
+/// ```
+#[inline]
+pub fn try_range_step_idx(start: usize, stop: usize, step: usize, end: usize) -> Result<RangeStepIdx, CyclicError> {
+    if start > stop {
+        return Err(CyclicError::InvertedBounds { start, end: stop });
+    }
+    if stop >= step {
+        return Err(CyclicError::BlockWiderThanStep { stop, step });
+    }
+    if step > end {
+        return Err(CyclicError::StepExceedsLength { step, len: end });
+    }
+    Ok(range_step_idx(start, stop, step, end))
+}
+
+/// Creates an iterator that sequentially outputs a value in the range with a step of n elements of type usize,
+/// where the range has any form of [`RangeBounds`].
+///
+/// The bounds follow the std semantics, see [`range_skip_bounds()`].
+/// An empty or invalid range gives an empty iterator, as in [`range_step_idx()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_step_idx_bounds;
+///
+/// let idx: Vec<usize> = range_step_idx_bounds(0..2, 4, 12).collect();
+/// assert_eq!(idx, [0, 1, 4, 5, 8, 9]);
+///
+/// let idx: Vec<usize> = range_step_idx_bounds(..=1, 4, 12).collect();
+/// assert_eq!(idx, [0, 1, 4, 5, 8, 9]);
+///
+/// ```
+#[inline]
+pub fn range_step_idx_bounds<R>(range: R, step: usize, end: usize) -> RangeStepIdx
+where
+    R: RangeBounds<usize>,
+{
+    match inclusive_bounds(&range) {
+        Ok(Some((start, stop))) => range_step_idx(start, stop, step, end),
+        _ => RangeStepIdx::empty(),
+    }
+}
+
+/// Creates an iterator that sequentially outputs a value in the range with a step of n elements of type usize,
+/// where the range has any form of [`RangeBounds`], or an error if the arguments are invalid.
+///
+/// The fallible version of the [`range_step_idx_bounds()`] function.
+/// An empty range gives an empty iterator.
+///
+/// # Errors
+///
+/// The same as [`try_range_step_idx()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step_idx_bounds, CyclicError};
+///
+/// let idx: Vec<usize> = try_range_step_idx_bounds(1..3, 7, 15).unwrap().collect();
+/// assert_eq!(idx, [1, 2, 8, 9]);
+///
+/// let err = try_range_step_idx_bounds(0.., 7, 15).unwrap_err();
+/// assert_eq!(err, CyclicError::BlockWiderThanStep { stop: usize::MAX, step: 7 });
+///
+/// ```
+#[inline]
+pub fn try_range_step_idx_bounds<R>(range: R, step: usize, end: usize) -> Result<RangeStepIdx, CyclicError>
+where
+    R: RangeBounds<usize>,
+{
+    match inclusive_bounds(&range)? {
+        Some((start, stop)) => try_range_step_idx(start, stop, step, end),
+        None => Ok(RangeStepIdx::empty()),
     }
 }
-/// Implements range methods (start, stop, step) for a vector.
+
+/// Implements range methods (start, stop, step) for a vector, a slice, an array, a boxed slice and a deque.
+///
+/// The slice implementation covers the arrays and the boxed slices,
+/// the deque implementation walks both of its internal halves.
 ///
 /// # Examples
 ///
@@ -349,22 +1562,101 @@ pub fn range_step_idx(start: usize, stop: usize, step: usize, end: usize) -> Ran
 /// # use iter_cyclic::RangeStepVec;
 ///
 /// let vec: Vec<i8> = (-10..24).collect();
-/// let iter = vec.range_step_iter(0, 2, 7);
+/// let iter = vec.range_step_copied(0, 2, 7);
 /// assert_eq!(iter.collect::<Vec<_>>(), [-10, -9, -8, -3, -2, -1, 4, 5, 6, 11, 12, 13]);
 ///
 ///```
+///
+///```
+/// # use iter_cyclic::RangeStepVec;
+/// use std::collections::VecDeque;
+///
+/// let mut arr = [0_u8; 8];
+/// arr[2..].range_step_value(0, 0, 3, 1);
+/// assert_eq!(arr, [0, 0, 1, 0, 0, 1, 0, 0]);
+///
+/// let mut deque: VecDeque<u8> = (3..8).collect();
+/// deque.extend([8, 9]);
+/// deque.push_front(2);
+/// deque.range_step_values(1, 2, 4, [20, 30, 60, 70].into_iter());
+/// assert_eq!(deque.range_step_vec(0, 0, 2), [2, 30, 6, 70]);
+///
+///```
 pub trait RangeStepVec<T> {
     /// Changes the elements of the range (start, stop, step) of the vector to the value of the argument.
     fn range_step_value(&mut self, start: usize, stop: usize, step: usize, val: T);
     /// Changes the range elements (start, stop, step) of a vector to iterator values.
     fn range_step_values(&mut self, start: usize, stop: usize, step: usize, iter: impl Iterator<Item = T>);
+    /// Changes the range elements (start, stop, step) to iterator values and reports the result.
+    ///
+    /// Unlike [`range_step_values()`](RangeStepVec::range_step_values), returns the number of written elements,
+    /// whether the pattern or the source ran out first, and the unconsumed rest of the source.
+    /// When both run out together the pattern is reported, the source is not polled past the last element.
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use iter_cyclic::{Exhausted, RangeStepVec};
+    ///
+    /// // Packets of 2 payload bytes after a 1-byte header.
+    /// let mut packet = [0xff_u8; 6];
+    /// let report = packet.range_step_write(1, 2, 3, 1..=7);
+    /// assert_eq!((report.written, report.exhausted), (4, Exhausted::Pattern));
+    /// assert_eq!(packet, [0xff, 1, 2, 0xff, 3, 4]);
+    ///
+    /// let report = packet.range_step_write(1, 2, 3, report.rest);
+    /// assert_eq!((report.written, report.exhausted), (3, Exhausted::Source));
+    /// assert_eq!(packet, [0xff, 5, 6, 0xff, 7, 4]);
+    ///
+    ///```
+    fn range_step_write<I>(&mut self, start: usize, stop: usize, step: usize, iter: I) -> RangeStepWrite<I>
+    where
+        I: Iterator<Item = T>;
+    /// Replaces each element of the range (start, stop, step) with the result of the function applied to it.
+    ///
+    /// The blocks are visited as contiguous runs, without bounds checks per element.
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use iter_cyclic::RangeStepVec;
+    ///
+    /// // Halves the gain of the left channel of an interleaved stereo buffer.
+    /// let mut frames: Vec<i16> = vec![100, 7, -40, 7, 64, 7];
+    /// frames.range_step_map(0, 0, 2, |sample| sample / 2);
+    /// assert_eq!(frames, [50, 7, -20, 7, 32, 7]);
+    ///
+    ///```
+    fn range_step_map(&mut self, start: usize, stop: usize, step: usize, f: impl FnMut(T) -> T);
+    /// Calls the function with the index and a mutable reference to each element of the range (start, stop, step).
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use iter_cyclic::RangeStepVec;
+    ///
+    /// let mut vec = vec![0_usize; 8];
+    /// vec.range_step_map_indexed(1, 2, 4, |idx, elem| *elem = idx * 10);
+    /// assert_eq!(vec, [0, 10, 20, 0, 0, 50, 60, 0]);
+    ///
+    ///```
+    fn range_step_map_indexed(&mut self, start: usize, stop: usize, step: usize, f: impl FnMut(usize, &mut T));
     /// Returns a new allocated vector containing elements in the range (start, stop, step) of the original vector.
     fn range_step_vec(&self, start: usize, stop: usize, step: usize) -> Vec<T>;
-    /// Returns a new iterator containing elements in the range (start, stop, step).
-    fn range_step_iter(&self, start: usize, stop: usize, step: usize) ->  Map<RangeStepIdx, Box<dyn Fn(usize) -> T + '_>>;
+    /// Returns a new iterator copying the elements in the range (start, stop, step).
+    fn range_step_copied(&self, start: usize, stop: usize, step: usize) -> RangeStepSliceIter<'_, T>;
+    /// Returns a new iterator copying the elements in the range (start, stop, step).
+    ///
+    /// With [`RangeStepIter`] in scope, a call on a vector resolves to the by-value
+    /// [`RangeStepIter::range_step_iter()`] and moves the vector.
+    #[deprecated(note = "clashes with `RangeStepIter::range_step_iter`, use `range_step_copied`")]
+    #[inline]
+    fn range_step_iter(&self, start: usize, stop: usize, step: usize) -> RangeStepSliceIter<'_, T> {
+        self.range_step_copied(start, stop, step)
+    }
 }
 
-impl<T> RangeStepVec<T> for Vec<T>
+impl<T> RangeStepVec<T> for [T]
 where
     T: Copy,
 {
@@ -382,73 +1674,244 @@ where
         }
     }
 
+    #[inline]
+    fn range_step_write<I>(&mut self, start: usize, stop: usize, step: usize, iter: I) -> RangeStepWrite<I>
+    where
+        I: Iterator<Item = T>,
+    {
+        write_values(range_step_idx(start, stop, step, self.len()), iter, |idx, val| self[idx] = val)
+    }
+
+    #[inline]
+    fn range_step_map(&mut self, start: usize, stop: usize, step: usize, mut f: impl FnMut(T) -> T) {
+        self.range_step_map_indexed(start, stop, step, |_, elem| *elem = f(*elem))
+    }
+
+    #[inline]
+    fn range_step_map_indexed(&mut self, start: usize, stop: usize, step: usize, f: impl FnMut(usize, &mut T)) {
+        let idx = range_step_idx(start, stop, step, self.len());
+        for_each_run_mut(self, &mut [], idx, f)
+    }
+
     #[inline]
     fn range_step_vec(&self, start: usize, stop: usize, step: usize) -> Vec<T> {
-        let mut vec: Vec<T> = Vec::new();
-        for idx in range_step_idx(start, stop, step, self.len()) {
+        let iter = range_step_idx(start, stop, step, self.len());
+        let mut vec: Vec<T> = Vec::with_capacity(iter.len());
+        for idx in iter {
             vec.push(self[idx]);
             //vec.push(unsafe { *self.get_unchecked(idx) });
         }
         vec
     }
 
-    // Dynamic dispatch cannot be inlined.
-    fn range_step_iter(&self, start: usize, stop: usize, step: usize) ->  Map<RangeStepIdx, Box<dyn Fn(usize) -> T + '_>> {
-        range_step_idx(start, stop, step, self.len()).map(Box::new(|idx| self[idx]))
+    #[inline]
+    fn range_step_copied(&self, start: usize, stop: usize, step: usize) -> RangeStepSliceIter<'_, T> {
+        RangeStepSliceIter::new(self.range_step_ref(start, stop, step))
+    }
+}
+
+impl<T> RangeStepVec<T> for Vec<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn range_step_value(&mut self, start: usize, stop: usize, step: usize, val: T) {
+        self.as_mut_slice().range_step_value(start, stop, step, val)
+    }
+
+    #[inline]
+    fn range_step_values(&mut self, start: usize, stop: usize, step: usize, iter: impl Iterator<Item = T>) {
+        self.as_mut_slice().range_step_values(start, stop, step, iter)
+    }
+
+    #[inline]
+    fn range_step_write<I>(&mut self, start: usize, stop: usize, step: usize, iter: I) -> RangeStepWrite<I>
+    where
+        I: Iterator<Item = T>,
+    {
+        self.as_mut_slice().range_step_write(start, stop, step, iter)
+    }
+
+    #[inline]
+    fn range_step_map(&mut self, start: usize, stop: usize, step: usize, f: impl FnMut(T) -> T) {
+        self.as_mut_slice().range_step_map(start, stop, step, f)
+    }
+
+    #[inline]
+    fn range_step_map_indexed(&mut self, start: usize, stop: usize, step: usize, f: impl FnMut(usize, &mut T)) {
+        self.as_mut_slice().range_step_map_indexed(start, stop, step, f)
+    }
+
+    #[inline]
+    fn range_step_vec(&self, start: usize, stop: usize, step: usize) -> Vec<T> {
+        self.as_slice().range_step_vec(start, stop, step)
+    }
+
+    #[inline]
+    fn range_step_copied(&self, start: usize, stop: usize, step: usize) -> RangeStepSliceIter<'_, T> {
+        self.as_slice().range_step_copied(start, stop, step)
+    }
+}
+
+impl<T> RangeStepVec<T> for VecDeque<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn range_step_value(&mut self, start: usize, stop: usize, step: usize, val: T) {
+        let (front, back) = self.as_mut_slices();
+        let split = front.len();
+        range_step_idx(start, stop, step, split + back.len()).for_each(|idx| {
+            *deque_elem_mut(front, back, idx) = val;
+        })
+    }
+
+    #[inline]
+    fn range_step_values(&mut self, start: usize, stop: usize, step: usize, mut iter: impl Iterator<Item = T>) {
+        let (front, back) = self.as_mut_slices();
+        for idx in range_step_idx(start, stop, step, front.len() + back.len()) {
+            *deque_elem_mut(front, back, idx) = if let Some(val) = iter.next() { val } else { break; };
+        }
+    }
+
+    #[inline]
+    fn range_step_write<I>(&mut self, start: usize, stop: usize, step: usize, iter: I) -> RangeStepWrite<I>
+    where
+        I: Iterator<Item = T>,
+    {
+        let (front, back) = self.as_mut_slices();
+        let idx = range_step_idx(start, stop, step, front.len() + back.len());
+        write_values(idx, iter, |idx, val| *deque_elem_mut(front, back, idx) = val)
+    }
+
+    #[inline]
+    fn range_step_map(&mut self, start: usize, stop: usize, step: usize, mut f: impl FnMut(T) -> T) {
+        self.range_step_map_indexed(start, stop, step, |_, elem| *elem = f(*elem))
+    }
+
+    #[inline]
+    fn range_step_map_indexed(&mut self, start: usize, stop: usize, step: usize, f: impl FnMut(usize, &mut T)) {
+        let (front, back) = self.as_mut_slices();
+        let idx = range_step_idx(start, stop, step, front.len() + back.len());
+        for_each_run_mut(front, back, idx, f)
+    }
+
+    #[inline]
+    fn range_step_vec(&self, start: usize, stop: usize, step: usize) -> Vec<T> {
+        let (front, back) = self.as_slices();
+        range_step_idx(start, stop, step, self.len())
+            .map(|idx| *deque_elem(front, back, idx))
+            .collect()
+    }
+
+    #[inline]
+    fn range_step_copied(&self, start: usize, stop: usize, step: usize) -> RangeStepSliceIter<'_, T> {
+        RangeStepSliceIter::new(self.range_step_ref(start, stop, step))
+    }
+}
+
+/// Calls the function on the elements of a deque split into its two halves, one run of consecutive indices at a time.
+#[inline]
+fn for_each_run_mut<T>(front: &mut [T], back: &mut [T], mut idx: RangeStepIdx, mut f: impl FnMut(usize, &mut T)) {
+    let split = front.len();
+    while let Some((first, last)) = idx.next_run() {
+        if first < split {
+            let end = split.min(last + 1);
+            for (idx, elem) in (first..end).zip(&mut front[first..end]) {
+                f(idx, elem);
+            }
+        }
+        if last >= split {
+            let first = first.max(split);
+            for (idx, elem) in (first..=last).zip(&mut back[first - split..=last - split]) {
+                f(idx, elem);
+            }
+        }
+    }
+}
+
+/// Returns the element of a deque split into its two halves.
+#[inline]
+fn deque_elem<'a, T>(front: &'a [T], back: &'a [T], idx: usize) -> &'a T {
+    match idx.checked_sub(front.len()) {
+        Some(idx) => &back[idx],
+        None => &front[idx],
+    }
+}
+
+/// Returns the mutable element of a deque split into its two halves.
+#[inline]
+fn deque_elem_mut<'a, T>(front: &'a mut [T], back: &'a mut [T], idx: usize) -> &'a mut T {
+    match idx.checked_sub(front.len()) {
+        Some(idx) => &mut back[idx],
+        None => &mut front[idx],
     }
 }
 
-/// An iterator created from a vector.
+/// An iterator created from a vector, a boxed slice or an array.
 #[derive(Clone, Debug)]
-pub struct RangeStepVecIter<T: Copy> {
-    vec: Vec<T>,
-    start: usize,
-    start_next: usize,
-    stop: usize,
-    step: usize,
-    step_next: usize,
-    end: usize,
-    once_flag: bool,
+pub struct RangeStepVecIter<T: Copy, S = Vec<T>> {
+    vec: S,
+    cursor: Cursor<usize>,
+    marker: PhantomData<T>,
 }
 
-impl<T: Copy> Iterator for RangeStepVecIter<T> {
+impl<T: Copy, S: AsRef<[T]>> Iterator for RangeStepVecIter<T, S> {
     type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.once_flag {
-            if self.step == 0 {
-                return None;
-            }
-            self.once_flag = false;
-            return Some(self.vec[self.start]);
-        }
+        self.cursor.next().map(|idx| self.vec.as_ref()[idx])
+    }
 
-        if self.start == self.stop {
-            if let Some(next_step) = self.step_next.checked_add(self.step) {
-                self.step_next = next_step;
-                if self.step_next > self.end {
-                    return None;
-                }
-            } else {
-                return None;
-            }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cursor.size_hint()
+    }
 
-            self.start_next += self.step;
-            self.start = self.start_next;
-            self.stop += self.step;
-            return Some(self.vec[self.start]);
-        }
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.nth(n).map(|idx| self.vec.as_ref()[idx])
+    }
 
-        self.start += 1;
-        Some(self.vec[self.start])
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T: Copy, S: AsRef<[T]>> DoubleEndedIterator for RangeStepVecIter<T, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor.next_back().map(|idx| self.vec.as_ref()[idx])
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.nth_back(n).map(|idx| self.vec.as_ref()[idx])
     }
 }
 
+impl<T: Copy, S: AsRef<[T]>> ExactSizeIterator for RangeStepVecIter<T, S> {}
+
+impl<T: Copy, S: AsRef<[T]>> FusedIterator for RangeStepVecIter<T, S> {}
+
+impl<T: Copy, S: AsRef<[T]>> RangeStepVecIter<T, S> {
+    #[inline]
+    fn new(vec: S, start: usize, stop: usize, step: usize) -> Self {
+        let cursor = step_cursor(start, stop, step, vec.as_ref().len());
+        RangeStepVecIter {
+            vec,
+            cursor,
+            marker: PhantomData,
+        }
+    }
+}
 
 /// An iterator created from a vector that sequentially outputs a value in the range with a step of n elements.
 ///
 /// Without dynamic dispatch.
+/// Implemented for a vector, a boxed slice and an array, which are moved into the iterator.
 /// Range,
 ///  start - the lower bound of the range (inclusive),
 ///  end - the upper bound of the range (inclusive).
@@ -464,10 +1927,16 @@ impl<T: Copy> Iterator for RangeStepVecIter<T> {
 /// let vec = vec_src.range_step_iter(0, 2, 5).collect::<Vec<_>>();
 /// assert_eq!(vec, [0, 1, 2, 5, 6, 7, 10, 11, 12, 15, 16, 17]);
 ///
+/// let arr: [u8; 12] = std::array::from_fn(|i| i as u8);
+/// assert_eq!(arr.range_step_iter(1, 1, 4).collect::<Vec<_>>(), [1, 5, 9]);
+///
+/// let boxed: Box<[u8]> = arr.into();
+/// assert_eq!(boxed.range_step_iter(3, 3, 4).rev().collect::<Vec<_>>(), [11, 7, 3]);
+///
 ///```
-pub trait RangeStepIter<T: Copy> {
+pub trait RangeStepIter<T: Copy>: Sized {
     /// Creates an iterator from a vector.
-    fn range_step_iter(self, start: usize, stop: usize, step: usize) -> RangeStepVecIter<T>;
+    fn range_step_iter(self, start: usize, stop: usize, step: usize) -> RangeStepVecIter<T, Self>;
 }
 
 impl<T> RangeStepIter<T> for Vec<T>
@@ -476,21 +1945,26 @@ where
 {
     #[inline]
     fn range_step_iter(self, start: usize, stop: usize, step: usize) -> RangeStepVecIter<T> {
-        let end = self.len();
-        RangeStepVecIter {
-            vec: self,
-            start,
-            start_next: start,
-            stop,
-            step: if start > stop || stop >= step || step > end {
-                0
-            } else {
-                step
-            },
-            step_next: step,
-            end,
-            once_flag: true,
-        }
+        RangeStepVecIter::new(self, start, stop, step)
+    }
+}
+
+impl<T> RangeStepIter<T> for Box<[T]>
+where
+    T: Copy + 'static,
+{
+    #[inline]
+    fn range_step_iter(self, start: usize, stop: usize, step: usize) -> RangeStepVecIter<T, Box<[T]>> {
+        RangeStepVecIter::new(self, start, stop, step)
     }
 }
 
+impl<T, const N: usize> RangeStepIter<T> for [T; N]
+where
+    T: Copy + 'static,
+{
+    #[inline]
+    fn range_step_iter(self, start: usize, stop: usize, step: usize) -> RangeStepVecIter<T, [T; N]> {
+        RangeStepVecIter::new(self, start, stop, step)
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{range_step_idx, CyclicError, RangeStepIdx, StridePattern};
+use std::iter::FusedIterator;
+
+/// One level of a nested stride, the blocks that fit into the window of the outer level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Level {
+    start: usize,
+    step: usize,
+    blocks: usize,
+}
+
+/// An iterator over the indices of nested stride patterns.
+///
+/// Each level selects the blocks of (start, stop, step) inside one block of the outer level,
+/// the outermost level inside the whole length.
+/// All blocks of a level have the same width, so the inner pattern repeats in each of them.
+///
+/// See the [`range_step_nested()`] function for more information.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NestedStride {
+    levels: Vec<Level>,
+    width: usize,
+    len: usize,
+    front: usize,
+    back: usize,
+}
+
+impl NestedStride {
+    #[inline]
+    fn try_new<L>(levels: L, end: usize) -> Result<Self, CyclicError>
+    where
+        L: IntoIterator<Item = (usize, usize, usize)>,
+    {
+        let mut width = end;
+        let mut count = 1;
+        let levels = levels
+            .into_iter()
+            .map(|(start, stop, step)| {
+                if start > stop {
+                    return Err(CyclicError::InvertedBounds { start, end: stop });
+                }
+                if stop >= step {
+                    return Err(CyclicError::BlockWiderThanStep { stop, step });
+                }
+                if step > width {
+                    return Err(CyclicError::StepExceedsLength { step, len: width });
+                }
+                let blocks = width / step;
+                count *= blocks;
+                width = stop - start + 1;
+                Ok(Level { start, step, blocks })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(NestedStride {
+            levels,
+            width,
+            len: count * width,
+            front: 0,
+            back: count * width,
+        })
+    }
+
+    #[inline]
+    fn empty() -> Self {
+        NestedStride {
+            levels: Vec::new(),
+            width: 0,
+            len: 0,
+            front: 0,
+            back: 0,
+        }
+    }
+
+    /// Returns the index at the position in the whole pattern of the iterator, counted from zero.
+    ///
+    /// The positions are the digits of the blocks of each level and of the offset in the innermost block.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step_nested;
+    ///
+    /// let iter = range_step_nested([(0, 49, 100), (0, 2, 7)], 300);
+    /// assert_eq!(iter.value_at(3), Some(7));
+    /// assert_eq!(iter.value_at(21), Some(100));
+    /// assert_eq!(iter.value_at(63), None);
+    ///
+    /// ```
+    #[inline]
+    pub fn value_at(&self, position: usize) -> Option<usize> {
+        if position >= self.len {
+            return None;
+        }
+        let mut rest = position / self.width;
+        let offset = position % self.width;
+        Some(self.levels.iter().rev().fold(offset, |index, level| {
+            let block = rest % level.blocks;
+            rest /= level.blocks;
+            index + level.start + block * level.step
+        }))
+    }
+}
+
+impl Iterator for NestedStride {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<usize> {
+        if n >= self.back - self.front {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n + 1;
+        self.value_at(self.front - 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for NestedStride {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        self.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<usize> {
+        if n >= self.back - self.front {
+            self.back = self.front;
+            return None;
+        }
+        self.back -= n + 1;
+        self.value_at(self.back)
+    }
+}
+
+impl ExactSizeIterator for NestedStride {}
+
+impl FusedIterator for NestedStride {}
+
+/// Creates an iterator over the indices of nested stride patterns of type usize.
+///
+/// Levels,
+///  (start, stop, step) of the blocks, the stop is inclusive,
+///  the first level selects the blocks in the whole length,
+///  each next level selects the blocks inside one block of the previous level.
+/// End,
+///  iterator length.
+///
+/// Each level follows the rules of [`range_step_idx()`] with the width of the outer block as the length,
+/// so every index is less than the end.
+/// An invalid level gives an empty iterator, without levels the iterator outputs all indices below the end.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_step_nested;
+///
+/// let idx: Vec<usize> = range_step_nested([(0, 11, 20), (0, 1, 4)], 45).collect();
+/// assert_eq!(idx, [0, 1, 4, 5, 8, 9, 20, 21, 24, 25, 28, 29]);
+///
+/// let idx: Vec<usize> = range_step_nested([(0, 49, 100), (0, 2, 7)], 200).collect();
+/// assert_eq!(idx.len(), 42);
+/// assert_eq!(idx[..4], [0, 1, 2, 7]);
+/// assert_eq!(idx[21..24], [100, 101, 102]);
+///
+/// ```
+#[inline]
+pub fn range_step_nested<L>(levels: L, end: usize) -> NestedStride
+where
+    L: IntoIterator<Item = (usize, usize, usize)>,
+{
+    NestedStride::try_new(levels, end).unwrap_or_else(|_| NestedStride::empty())
+}
+
+/// Creates an iterator over the indices of nested stride patterns of type usize,
+/// or an error if a level is invalid.
+///
+/// The fallible version of the [`range_step_nested()`] function, which returns an empty iterator instead.
+///
+/// # Errors
+///
+/// [`CyclicError::InvertedBounds`] if the start value of a level is greater than its stop value,
+/// [`CyclicError::BlockWiderThanStep`] if the stop value of a level is not less than its step,
+/// [`CyclicError::StepExceedsLength`] if the step of a level is greater than the width of the outer block,
+/// or than the iterator length for the first level.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{try_range_step_nested, CyclicError};
+///
+/// assert_eq!(try_range_step_nested([(0, 9, 20), (0, 1, 4)], 45).unwrap().len(), 8);
+///
+/// let err = try_range_step_nested([(0, 9, 20), (0, 1, 12)], 45).unwrap_err();
+/// assert_eq!(err, CyclicError::StepExceedsLength { step: 12, len: 10 });
+///
+/// ```
+#[inline]
+pub fn try_range_step_nested<L>(levels: L, end: usize) -> Result<NestedStride, CyclicError>
+where
+    L: IntoIterator<Item = (usize, usize, usize)>,
+{
+    NestedStride::try_new(levels, end)
+}
+
+/// An iterator over the indices of a pattern taken at the positions selected by another pattern.
+///
+/// See the [`StridePattern::compose()`] method.
+#[derive(Clone, Copy, Debug)]
+pub struct Compose {
+    outer: RangeStepIdx,
+    inner: RangeStepIdx,
+}
+
+impl Iterator for Compose {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        self.inner.next().and_then(|position| self.outer.value_at(position))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<usize> {
+        self.inner.nth(n).and_then(|position| self.outer.value_at(position))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Compose {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        self.inner.next_back().and_then(|position| self.outer.value_at(position))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<usize> {
+        self.inner.nth_back(n).and_then(|position| self.outer.value_at(position))
+    }
+}
+
+impl ExactSizeIterator for Compose {}
+
+impl FusedIterator for Compose {}
+
+impl StridePattern {
+    /// Creates an iterator that applies the inner pattern to the positions produced by this pattern.
+    ///
+    /// The n-th index of the result is the index of this pattern at the n-th position of the inner pattern.
+    /// The end of the inner pattern is limited to the number of indices of this pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::StridePattern;
+    ///
+    /// let outer = StridePattern::new(0, 2, 7, 22);
+    /// let idx: Vec<usize> = outer.iter().collect();
+    /// assert_eq!(idx, [0, 1, 2, 7, 8, 9, 14, 15, 16]);
+    ///
+    /// let inner = StridePattern::new(0, 0, 2, usize::MAX);
+    /// let idx: Vec<usize> = outer.compose(&inner).collect();
+    /// assert_eq!(idx, [0, 2, 8, 14]);
+    ///
+    /// ```
+    #[inline]
+    pub fn compose(&self, inner: &StridePattern) -> Compose {
+        let outer = self.iter();
+        Compose {
+            inner: range_step_idx(inner.start, inner.stop, inner.step, inner.end.min(outer.len())),
+            outer,
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::cursor::Cursor;
+
+/// The behavior of a cyclic iterator when the next block does not fit into the output type.
+///
+/// The iterator stays finite under every policy,
+/// see the [`RangeSkip::wrapping()`](crate::RangeSkip::wrapping) method to continue modulo the range of the type.
+/// See the [`RangeSkip::overflow()`](crate::RangeSkip::overflow)
+/// and [`RangeStep::overflow()`](crate::RangeStep::overflow) methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::{range_skip_bounds, OverflowPolicy};
+///
+/// let iter = range_skip_bounds(250_u8..=252, 2);
+/// assert_eq!(iter.clone().collect::<Vec<_>>(), [250, 251, 252]);
+///
+/// let saturate = iter.clone().overflow(OverflowPolicy::Saturate);
+/// assert_eq!(saturate.collect::<Vec<_>>(), [250, 251, 252, 255]);
+///
+/// let wrap = iter.wrapping();
+/// assert_eq!(wrap.take(9).collect::<Vec<_>>(), [250, 251, 252, 255, 0, 1, 4, 5, 6]);
+///
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Stops before the block that does not fit.
+    #[default]
+    Stop,
+    /// Panics when the iterator moves to the block that does not fit,
+    /// from the back it is the first block.
+    Panic,
+    /// Outputs the block that does not fit truncated at the maximum value of the type.
+    Saturate,
+}
+
+/// Offsets of a typed cyclic iterator under an overflow policy.
+///
+/// The offsets are counted from the minimum of the type, or from the maximum if `mirror` is set.
+/// `limit` is the index of the last element allowed by the block and value limits,
+/// `None` if no element is allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Span {
+    cursor: Cursor<u128>,
+    mirror: u128,
+    max: u128,
+    fit: Option<u128>,
+    limit: Option<u128>,
+    cyclic: bool,
+    policy: OverflowPolicy,
+    /// Continues modulo the range of the type instead of the policy.
+    wrap: bool,
+}
+
+impl Span {
+    /// Creates the offsets of the blocks that fit into `max`.
+    #[inline]
+    pub(crate) fn new(cursor: Cursor<u128>, max: u128, cyclic: bool, descending: bool) -> Self {
+        Span {
+            fit: cursor.last_index(),
+            limit: Some(u128::MAX),
+            cursor,
+            mirror: if descending { max } else { 0 },
+            max,
+            cyclic,
+            policy: OverflowPolicy::Stop,
+            wrap: false,
+        }
+    }
+
+    /// Creates a span without offsets.
+    #[inline]
+    pub(crate) fn empty() -> Self {
+        Self::empty_of(0, false)
+    }
+
+    /// Creates a span without offsets for an empty or invalid pattern of a type with the maximum `max`.
+    #[inline]
+    pub(crate) fn empty_of(max: u128, descending: bool) -> Self {
+        Self::new(Cursor::empty(), max, false, descending)
+    }
+
+    #[inline]
+    pub(crate) fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+        self.update_end();
+    }
+
+    /// Continues the blocks modulo the range of the type, the span never ends without a limit.
+    #[inline]
+    pub(crate) fn set_wrap(&mut self) {
+        self.policy = OverflowPolicy::Stop;
+        self.wrap = true;
+        self.update_end();
+    }
+
+    /// Limits the span to the first `blocks` blocks.
+    #[inline]
+    pub(crate) fn limit_blocks(&mut self, blocks: u128) {
+        self.limit = self.limit.min(self.cursor.block_last_index(blocks));
+        self.update_end();
+    }
+
+    /// Limits the span to the offsets not greater than `max`, cutting the last block if `partial` is set.
+    ///
+    /// The block is always cut at the limit of the type, as with the saturation.
+    #[inline]
+    pub(crate) fn limit_offset(&mut self, max: u128, partial: bool) {
+        let max = max ^ self.mirror;
+        let last = if partial || max == self.max {
+            self.cursor.last_index_below(max)
+        } else {
+            self.cursor.block_last_index(self.cursor.blocks_below(max))
+        };
+        self.limit = self.limit.min(last);
+        self.update_end();
+    }
+
+    #[inline]
+    fn update_end(&mut self) {
+        if self.fit.is_some() {
+            let (last, mask) = self.end();
+            self.cursor.set_end(last, mask);
+        }
+    }
+
+    /// Returns the index of the last element under the policy and the limits, and the mask of the offsets.
+    #[inline]
+    fn end(&self) -> (Option<u128>, u128) {
+        if self.fit.is_none() {
+            return (None, u128::MAX);
+        }
+        let (last, mask) = match self.policy {
+            _ if self.wrap && self.cyclic => (Some(u128::MAX), self.max),
+            OverflowPolicy::Saturate if self.cyclic => (self.cursor.last_index_below(self.max), u128::MAX),
+            _ => (self.fit, u128::MAX),
+        };
+        (last.min(self.limit), mask)
+    }
+
+    /// Returns the position of the offset in the whole span.
+    #[inline]
+    pub(crate) fn position_of(&self, offset: u128) -> Option<u128> {
+        self.cursor.index_of(offset ^ self.mirror, self.end().0?)
+    }
+
+    /// Returns the offset at the position in the whole span.
+    #[inline]
+    pub(crate) fn offset_at(&self, idx: u128) -> Option<u128> {
+        self.cursor
+            .offset_at(idx, self.end().0?)
+            .map(|offset| offset ^ self.mirror)
+    }
+
+    /// Returns the offsets of the type that the whole span skips, in the order of the span.
+    ///
+    /// A span without blocks, from an empty or invalid pattern, skips nothing.
+    #[inline]
+    pub(crate) fn gaps(&self) -> Spans {
+        if self.fit.is_none() {
+            return Spans::empty();
+        }
+        let (last, _) = self.end();
+        Spans::new(self.cursor.gaps(last, self.max), self.max, self.mirror != 0)
+    }
+
+    #[inline]
+    fn check_overflow(&self) {
+        if self.policy == OverflowPolicy::Panic
+            && self.cyclic
+            && self.limit > self.fit
+            && self.fit.is_some_and(|fit| self.cursor.front_reached(fit))
+        {
+            panic!("the next block overflows the output type");
+        }
+    }
+
+    /// Going backwards, the block that does not fit is the first one.
+    #[inline]
+    fn check_back_overflow(&self) {
+        if self.policy == OverflowPolicy::Panic && self.cyclic && self.limit > self.fit && self.fit.is_some() {
+            panic!("the next block overflows the output type");
+        }
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self) -> Option<u128> {
+        let offset = self.cursor.next();
+        if offset.is_none() {
+            self.check_overflow();
+        }
+        offset.map(|offset| offset ^ self.mirror)
+    }
+
+    #[inline]
+    pub(crate) fn next_back(&mut self) -> Option<u128> {
+        self.check_back_overflow();
+        self.cursor.next_back().map(|offset| offset ^ self.mirror)
+    }
+
+    #[inline]
+    pub(crate) fn nth(&mut self, n: usize) -> Option<u128> {
+        let offset = self.cursor.nth(n);
+        if offset.is_none() {
+            self.check_overflow();
+        }
+        offset.map(|offset| offset ^ self.mirror)
+    }
+
+    #[inline]
+    pub(crate) fn nth_back(&mut self, n: usize) -> Option<u128> {
+        self.check_back_overflow();
+        self.cursor.nth_back(n).map(|offset| offset ^ self.mirror)
+    }
+
+    #[inline]
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cursor.size_hint()
+    }
+
+    /// Returns the lowest and the highest offsets of the next run of consecutive offsets.
+    #[inline]
+    pub(crate) fn next_run(&mut self) -> Option<(u128, u128)> {
+        let run = self.cursor.next_run();
+        if run.is_none() {
+            self.check_overflow();
+        }
+        run.map(|run| self.mirrored_run(run))
+    }
+
+    #[inline]
+    pub(crate) fn next_back_run(&mut self) -> Option<(u128, u128)> {
+        self.check_back_overflow();
+        self.cursor.next_back_run().map(|run| self.mirrored_run(run))
+    }
+
+    #[inline]
+    pub(crate) fn runs_hint(&self) -> (usize, Option<usize>) {
+        self.cursor.runs_hint()
+    }
+
+    #[inline]
+    fn mirrored_run(&self, (start, end): (u128, u128)) -> (u128, u128) {
+        if self.mirror == 0 {
+            (start, end)
+        } else {
+            (end ^ self.mirror, start ^ self.mirror)
+        }
+    }
+}
+
+/// Consecutive spans without an overflow policy, iterated as one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Spans {
+    parts: [Span; 3],
+}
+
+impl Spans {
+    #[inline]
+    pub(crate) fn new(cursors: [Cursor<u128>; 3], max: u128, descending: bool) -> Self {
+        Spans {
+            parts: cursors.map(|cursor| Span::new(cursor, max, false, descending)),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn empty() -> Self {
+        Spans::from(Span::empty())
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self) -> Option<u128> {
+        self.parts.iter_mut().find_map(Span::next)
+    }
+
+    #[inline]
+    pub(crate) fn next_back(&mut self) -> Option<u128> {
+        self.parts.iter_mut().rev().find_map(Span::next_back)
+    }
+
+    #[inline]
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_hints(self.parts.iter().map(Span::size_hint))
+    }
+
+    #[inline]
+    pub(crate) fn next_run(&mut self) -> Option<(u128, u128)> {
+        self.parts.iter_mut().find_map(Span::next_run)
+    }
+
+    #[inline]
+    pub(crate) fn next_back_run(&mut self) -> Option<(u128, u128)> {
+        self.parts.iter_mut().rev().find_map(Span::next_back_run)
+    }
+
+    #[inline]
+    pub(crate) fn runs_hint(&self) -> (usize, Option<usize>) {
+        sum_hints(self.parts.iter().map(Span::runs_hint))
+    }
+}
+
+impl From<Span> for Spans {
+    #[inline]
+    fn from(span: Span) -> Self {
+        Spans {
+            parts: [span, Span::empty(), Span::empty()],
+        }
+    }
+}
+
+#[inline]
+fn sum_hints(hints: impl Iterator<Item = (usize, Option<usize>)>) -> (usize, Option<usize>) {
+    hints.fold((0, Some(0)), |(low, high), (part_low, part_high)| {
+        (
+            low.saturating_add(part_low),
+            high.zip(part_high).and_then(|(high, part_high)| high.checked_add(part_high)),
+        )
+    })
+}
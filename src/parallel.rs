@@ -0,0 +1,119 @@
+use crate::{try_range_step_idx, RangeStepBlocks};
+use std::mem;
+use std::thread;
+
+/// Implements thread-parallel range methods (start, stop, step) for a slice, without external dependencies.
+///
+/// The blocks of the pattern are split into contiguous groups of nearly equal size, one per thread,
+/// and each group is processed on a scoped thread of the standard library.
+/// The thread count is limited to the number of blocks, zero threads count as one,
+/// the calling thread processes the last group.
+/// The results are identical to the sequential methods, only the order of the calls differs between the groups.
+/// A panic in the function is propagated to the caller once all threads have finished.
+///
+/// The slice implementation covers the vectors, the arrays and the boxed slices.
+///
+/// # Examples
+///
+///```
+/// use iter_cyclic::{RangeStepPar, RangeStepVec};
+///
+/// // Gain of the right channel of an interleaved stereo buffer, on 4 threads.
+/// let mut frames: Vec<i32> = (0..1000).collect();
+/// let mut expected = frames.clone();
+/// frames.par_range_step_map(1, 1, 2, 4, |sample| sample * 3);
+/// expected.range_step_map(1, 1, 2, |sample| sample * 3);
+/// assert_eq!(frames, expected);
+///
+///```
+pub trait RangeStepPar<T: Send> {
+    /// Calls the function with the index and a mutable reference to each element of the range (start, stop, step),
+    /// on the given number of threads.
+    fn par_range_step_for_each(&mut self, start: usize, stop: usize, step: usize, threads: usize, f: impl Fn(usize, &mut T) + Sync);
+
+    /// Replaces each element of the range (start, stop, step) with the result of the function applied to it,
+    /// on the given number of threads.
+    fn par_range_step_map(&mut self, start: usize, stop: usize, step: usize, threads: usize, f: impl Fn(T) -> T + Sync)
+    where
+        T: Copy;
+
+    /// Calls the function with each block of the range (start, stop, step) as a mutable subslice,
+    /// on the given number of threads.
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use iter_cyclic::RangeStepPar;
+    ///
+    /// // Records of 3 keys and 1 checksum byte, sorts the keys of each record.
+    /// let mut records: Vec<u8> = vec![3, 1, 2, 0xa0, 9, 7, 8, 0xb0, 6, 5, 4, 0xc0];
+    /// records.par_range_step_chunks_mut(0, 2, 4, 2, |keys| keys.sort_unstable());
+    /// assert_eq!(records, [1, 2, 3, 0xa0, 7, 8, 9, 0xb0, 4, 5, 6, 0xc0]);
+    ///
+    ///```
+    fn par_range_step_chunks_mut(&mut self, start: usize, stop: usize, step: usize, threads: usize, f: impl Fn(&mut [T]) + Sync);
+}
+
+impl<T: Send> RangeStepPar<T> for [T] {
+    #[inline]
+    fn par_range_step_for_each(&mut self, start: usize, stop: usize, step: usize, threads: usize, f: impl Fn(usize, &mut T) + Sync) {
+        par_blocks(self, start, stop, step, threads, |first, block| {
+            for (idx, elem) in (first..).zip(block) {
+                f(idx, elem);
+            }
+        })
+    }
+
+    #[inline]
+    fn par_range_step_map(&mut self, start: usize, stop: usize, step: usize, threads: usize, f: impl Fn(T) -> T + Sync)
+    where
+        T: Copy,
+    {
+        par_blocks(self, start, stop, step, threads, |_, block| {
+            for elem in block {
+                *elem = f(*elem);
+            }
+        })
+    }
+
+    #[inline]
+    fn par_range_step_chunks_mut(&mut self, start: usize, stop: usize, step: usize, threads: usize, f: impl Fn(&mut [T]) + Sync) {
+        par_blocks(self, start, stop, step, threads, |_, block| f(block))
+    }
+}
+
+/// Calls the function with the index of the first element and the subslice of each block,
+/// the blocks are split into one contiguous group per thread.
+fn par_blocks<T, F>(slice: &mut [T], start: usize, stop: usize, step: usize, threads: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    if try_range_step_idx(start, stop, step, slice.len()).is_err() {
+        return;
+    }
+    let blocks = slice.len() / step;
+    let threads = threads.clamp(1, blocks);
+    let f = &f;
+    thread::scope(|scope| {
+        let mut rest = &mut slice[..blocks * step];
+        let mut offset = 0;
+        for group in 0..threads {
+            let count = blocks / threads + usize::from(group < blocks % threads);
+            let (part, tail) = mem::take(&mut rest).split_at_mut(count * step);
+            rest = tail;
+            let first = offset + start;
+            offset += count * step;
+            let mut work = move || {
+                for (block, chunk) in part.range_step_chunks_mut(start, stop, step).enumerate() {
+                    f(first + block * step, chunk);
+                }
+            };
+            if group + 1 == threads {
+                work();
+            } else {
+                scope.spawn(work);
+            }
+        }
+    });
+}
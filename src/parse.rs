@@ -0,0 +1,300 @@
+use crate::error::{ParsePatternError, ParsePatternErrorKind};
+use crate::pattern::{SkipPattern, StepPattern, StridePattern};
+use num::{CheckedSub, One};
+use num_convert::TryToByAdd;
+use std::str::FromStr;
+
+/// The distance between the blocks as written in the string.
+#[derive(Clone, Copy, Debug)]
+enum Period {
+    Step(usize),
+    Skip(usize),
+}
+
+/// A block pattern as written in the string, the stop is inclusive.
+#[derive(Clone, Copy, Debug)]
+struct Parsed<T> {
+    start: T,
+    stop: T,
+    stop_at: usize,
+    period: Period,
+    period_at: usize,
+    len: Option<(usize, usize)>,
+}
+
+impl<T> Parsed<T>
+where
+    T: Copy + TryToByAdd,
+{
+    /// Returns the number of values in the block minus one, or an error if the block is inverted.
+    #[inline]
+    fn width(&self) -> Result<u128, ParsePatternError> {
+        match (self.start.try_into_u128(), self.stop.try_into_u128()) {
+            (Some(start), Some(stop)) => stop
+                .checked_sub(start)
+                .ok_or(error(self.stop_at, ParsePatternErrorKind::InvertedBounds)),
+            _ => Ok(u128::MAX),
+        }
+    }
+
+    #[inline]
+    fn step(&self) -> Result<usize, ParsePatternError> {
+        match self.period {
+            Period::Step(step) => Ok(step),
+            Period::Skip(skip) => self
+                .width()?
+                .checked_add(1 + skip as u128)
+                .and_then(|step| usize::try_from(step).ok())
+                .ok_or(error(self.period_at, ParsePatternErrorKind::Overflow)),
+        }
+    }
+
+    #[inline]
+    fn skip(&self) -> Result<usize, ParsePatternError> {
+        match self.period {
+            Period::Skip(skip) => Ok(skip),
+            Period::Step(step) => (step as u128)
+                .checked_sub(self.width()?)
+                .and_then(|gap| gap.checked_sub(1))
+                .map(|skip| skip as usize)
+                .ok_or(error(self.period_at, ParsePatternErrorKind::StepTooSmall)),
+        }
+    }
+
+    #[inline]
+    fn no_len(&self) -> Result<(), ParsePatternError> {
+        match self.len {
+            Some((_, at)) => Err(error(at, ParsePatternErrorKind::UnexpectedLen)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[inline]
+fn error(position: usize, kind: ParsePatternErrorKind) -> ParsePatternError {
+    ParsePatternError { position, kind }
+}
+
+/// A scanner over the pattern string.
+struct Scanner<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    #[inline]
+    fn rest(&self) -> &str {
+        &self.src[self.pos..]
+    }
+
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    #[inline]
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.rest().starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    /// Eats the keyword if it is followed by whitespace or by the end of the input.
+    #[inline]
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_whitespace();
+        let found = self
+            .rest()
+            .strip_prefix(keyword)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace));
+        if found {
+            self.pos += keyword.len();
+        }
+        found
+    }
+
+    /// Parses a number with an optional sign, returns it with its position.
+    #[inline]
+    fn number<N: FromStr>(&mut self) -> Result<(N, usize), ParsePatternError> {
+        self.skip_whitespace();
+        let at = self.pos;
+        let rest = &self.src[at..];
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..].len() - rest[sign..].trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return Err(error(at, ParsePatternErrorKind::ExpectedNumber));
+        }
+        self.pos += sign + digits;
+        let number = rest[..sign + digits]
+            .parse()
+            .map_err(|_| error(at, ParsePatternErrorKind::InvalidNumber))?;
+        Ok((number, at))
+    }
+}
+
+/// Parses `start..=stop`, `start..stop` or `start:stop:step`, followed by `/step` or `skip n`
+/// for the range forms, and by an optional `len n`.
+fn parse<T>(src: &str) -> Result<Parsed<T>, ParsePatternError>
+where
+    T: FromStr + PartialOrd + Copy + CheckedSub + One,
+{
+    let mut scanner = Scanner { src, pos: 0 };
+    let (start, _) = scanner.number::<T>()?;
+    let (stop, stop_at, period, period_at) = if scanner.eat(":") {
+        let (stop, stop_at) = scanner.number::<T>()?;
+        if !scanner.eat(":") {
+            return Err(error(scanner.pos, ParsePatternErrorKind::ExpectedBounds));
+        }
+        let (step, step_at) = scanner.number()?;
+        (exclusive(start, stop, stop_at)?, stop_at, Period::Step(step), step_at)
+    } else {
+        let inclusive = if scanner.eat("..=") {
+            true
+        } else if scanner.eat("..") {
+            false
+        } else {
+            return Err(error(scanner.pos, ParsePatternErrorKind::ExpectedBounds));
+        };
+        let (stop, stop_at) = scanner.number::<T>()?;
+        let stop = if inclusive {
+            stop
+        } else {
+            exclusive(start, stop, stop_at)?
+        };
+        if scanner.eat("/") {
+            let (step, step_at) = scanner.number()?;
+            (stop, stop_at, Period::Step(step), step_at)
+        } else if scanner.eat_keyword("skip") {
+            let (skip, skip_at) = scanner.number()?;
+            (stop, stop_at, Period::Skip(skip), skip_at)
+        } else {
+            return Err(error(scanner.pos, ParsePatternErrorKind::ExpectedStep));
+        }
+    };
+    let len = if scanner.eat_keyword("len") {
+        Some(scanner.number()?)
+    } else {
+        None
+    };
+    scanner.skip_whitespace();
+    if scanner.pos < src.len() {
+        return Err(error(scanner.pos, ParsePatternErrorKind::TrailingInput));
+    }
+    Ok(Parsed {
+        start,
+        stop,
+        stop_at,
+        period,
+        period_at,
+        len,
+    })
+}
+
+/// Converts the exclusive stop to the inclusive one.
+#[inline]
+fn exclusive<T>(start: T, stop: T, stop_at: usize) -> Result<T, ParsePatternError>
+where
+    T: PartialOrd + CheckedSub + One,
+{
+    if start > stop {
+        return Err(error(stop_at, ParsePatternErrorKind::InvertedBounds));
+    }
+    stop.checked_sub(&T::one())
+        .filter(|stop| *stop >= start)
+        .ok_or(error(stop_at, ParsePatternErrorKind::EmptyBlock))
+}
+
+/// Parses the pattern from a string.
+///
+/// The block is written as `start..=stop` (inclusive), `start..stop` (exclusive)
+/// or `start:stop:step` (exclusive, as the slices of NumPy).
+/// The range forms are followed by `/step`, the distance between the starts of the blocks,
+/// or by `skip n`, the number of values between the blocks.
+/// The length is given by `len n`, without it the pattern covers the whole `usize` range.
+/// An inverted inclusive block, as formatted by an empty pattern, is parsed as it is.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::StridePattern;
+///
+/// let pattern: StridePattern = "0..=2/7 len 22".parse().unwrap();
+/// assert_eq!(pattern, StridePattern::new(0, 2, 7, 22));
+/// assert_eq!("0:3:7 len 22".parse(), Ok(pattern));
+/// assert_eq!("0..3 skip 4 len 22".parse(), Ok(pattern));
+/// assert_eq!(pattern.to_string().parse(), Ok(pattern));
+///
+/// assert_eq!("0..=2/7".parse::<StridePattern>().unwrap().end, usize::MAX);
+/// assert_eq!("5..=2/7".parse(), Ok(StridePattern::new(5, 2, 7, usize::MAX)));
+///
+/// ```
+impl FromStr for StridePattern {
+    type Err = ParsePatternError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let parsed = parse::<usize>(src)?;
+        let (end, _) = parsed.len.unwrap_or((usize::MAX, 0));
+        Ok(StridePattern::new(parsed.start, parsed.stop, parsed.step()?, end))
+    }
+}
+
+/// Parses the pattern from a string.
+///
+/// The syntax is the one of the [`StridePattern`] without the length,
+/// a step is converted to the number of skipped values.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::SkipPattern;
+///
+/// let pattern: SkipPattern<u8> = "0..6 skip 200".parse().unwrap();
+/// assert_eq!(pattern, SkipPattern::new(0, 5, 200));
+/// assert_eq!("0..=5/206".parse(), Ok(pattern));
+/// assert_eq!(pattern.into_iter().last(), Some(211));
+///
+/// ```
+impl<T> FromStr for SkipPattern<T>
+where
+    T: FromStr + PartialOrd + Copy + CheckedSub + One + TryToByAdd,
+{
+    type Err = ParsePatternError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let parsed = parse::<T>(src)?;
+        parsed.no_len()?;
+        Ok(SkipPattern::new(parsed.start, parsed.stop, parsed.skip()?))
+    }
+}
+
+/// Parses the pattern from a string.
+///
+/// The syntax is the one of the [`StridePattern`] without the length,
+/// a number of skipped values is converted to the step.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::StepPattern;
+///
+/// let pattern: StepPattern<i8> = "-128..=-127/100".parse().unwrap();
+/// assert_eq!(pattern.into_iter().collect::<Vec<_>>(), [-128, -127, -28, -27]);
+/// assert_eq!("-128:-126:100".parse(), Ok(pattern));
+///
+/// ```
+impl<T> FromStr for StepPattern<T>
+where
+    T: FromStr + PartialOrd + Copy + CheckedSub + One + TryToByAdd,
+{
+    type Err = ParsePatternError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let parsed = parse::<T>(src)?;
+        parsed.no_len()?;
+        Ok(StepPattern::new(parsed.start, parsed.stop, parsed.step()?))
+    }
+}
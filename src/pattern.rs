@@ -0,0 +1,209 @@
+use crate::{range_skip_bounds, range_step_idx, try_range_step_bounds, RangeSkip, RangeStep, RangeStepIdx};
+use num::{Bounded, CheckedAdd, CheckedSub, One};
+use num_convert::{TryFromByAdd, TryToByAdd};
+use std::fmt::{self, Debug, Display};
+
+/// A pattern of [`range_step_idx()`], separate from the state of the iterator.
+///
+/// The pattern can be stored, compared, hashed and iterated many times.
+/// The stop is inclusive, the end is the length of the iterated space.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::StridePattern;
+/// use std::collections::HashMap;
+///
+/// let pattern = StridePattern::new(0, 2, 7, 22);
+/// assert_eq!(pattern.into_iter().collect::<Vec<_>>(), [0, 1, 2, 7, 8, 9, 14, 15, 16]);
+/// assert_eq!(pattern.to_string(), "0..=2/7 len 22");
+///
+/// let mut masks = HashMap::new();
+/// masks.insert(pattern, "left");
+/// assert_eq!(masks[&StridePattern::new(0, 2, 7, 22)], "left");
+///
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StridePattern {
+    /// The first index of the block.
+    pub start: usize,
+    /// The last index of the block (inclusive).
+    pub stop: usize,
+    /// The distance between the starts of the blocks.
+    pub step: usize,
+    /// The length of the iterated space.
+    pub end: usize,
+}
+
+impl StridePattern {
+    /// Creates a pattern, see the [`range_step_idx()`] function for the meaning of the values.
+    #[inline]
+    pub const fn new(start: usize, stop: usize, step: usize, end: usize) -> Self {
+        StridePattern {
+            start,
+            stop,
+            step,
+            end,
+        }
+    }
+
+    /// Creates an iterator over the pattern.
+    #[inline]
+    pub fn iter(&self) -> RangeStepIdx {
+        range_step_idx(self.start, self.stop, self.step, self.end)
+    }
+}
+
+impl IntoIterator for StridePattern {
+    type Item = usize;
+    type IntoIter = RangeStepIdx;
+
+    #[inline]
+    fn into_iter(self) -> RangeStepIdx {
+        self.iter()
+    }
+}
+
+/// Formats the pattern in the syntax of its [`FromStr`](std::str::FromStr) implementation,
+/// the length is omitted if it is `usize::MAX`.
+impl Display for StridePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}/{}", self.start, self.stop, self.step)?;
+        if self.end != usize::MAX {
+            write!(f, " len {}", self.end)?;
+        }
+        Ok(())
+    }
+}
+
+/// A pattern of [`range_skip_bounds()`], separate from the state of the iterator.
+///
+/// The end is inclusive.
+/// An inverted block, with the start value greater than the end value, gives an empty iterator.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::SkipPattern;
+///
+/// let pattern = SkipPattern::new(0_u8, 5, 200);
+/// assert_eq!(pattern.into_iter().count(), 12);
+/// assert_eq!(pattern.into_iter().last(), Some(211));
+/// assert_eq!(pattern.to_string(), "0..=5 skip 200");
+///
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SkipPattern<T> {
+    /// The first value of the block.
+    pub start: T,
+    /// The last value of the block (inclusive).
+    pub end: T,
+    /// The number of values skipped between the blocks.
+    pub skip: usize,
+}
+
+impl<T> SkipPattern<T> {
+    /// Creates a pattern, see the [`range_skip_bounds()`] function for the meaning of the values.
+    #[inline]
+    pub const fn new(start: T, end: T, skip: usize) -> Self {
+        SkipPattern { start, end, skip }
+    }
+}
+
+impl<T> SkipPattern<T>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Bounded + TryToByAdd,
+{
+    /// Creates an iterator over the pattern.
+    #[inline]
+    pub fn iter(&self) -> RangeSkip<T> {
+        if self.start > self.end {
+            return RangeSkip::empty(false);
+        }
+        range_skip_bounds(self.start..=self.end, self.skip)
+    }
+}
+
+impl<T> IntoIterator for SkipPattern<T>
+where
+    T: PartialOrd + Copy + Display + CheckedAdd + CheckedSub + One + Bounded + TryToByAdd + TryFromByAdd,
+{
+    type Item = T;
+    type IntoIter = RangeSkip<T>;
+
+    #[inline]
+    fn into_iter(self) -> RangeSkip<T> {
+        self.iter()
+    }
+}
+
+/// Formats the pattern in the syntax of its [`FromStr`](std::str::FromStr) implementation.
+impl<T: Display> Display for SkipPattern<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={} skip {}", self.start, self.end, self.skip)
+    }
+}
+
+/// A pattern of [`range_step()`](crate::range_step), separate from the state of the iterator.
+///
+/// The stop is inclusive.
+/// An invalid pattern gives an empty iterator, see [`try_range_step_bounds()`] for the rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::StepPattern;
+///
+/// let pattern = StepPattern::new(-128_i8, -127, 100);
+/// assert_eq!(pattern.into_iter().collect::<Vec<_>>(), [-128, -127, -28, -27]);
+/// assert_eq!(pattern.to_string(), "-128..=-127/100");
+///
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StepPattern<T> {
+    /// The first value of the block.
+    pub start: T,
+    /// The last value of the block (inclusive).
+    pub stop: T,
+    /// The distance between the starts of the blocks.
+    pub step: usize,
+}
+
+impl<T> StepPattern<T> {
+    /// Creates a pattern, see the [`range_step()`](crate::range_step) function for the meaning of the values.
+    #[inline]
+    pub const fn new(start: T, stop: T, step: usize) -> Self {
+        StepPattern { start, stop, step }
+    }
+}
+
+impl<T> StepPattern<T>
+where
+    T: Copy + Debug + TryToByAdd + TryFromByAdd + Bounded + PartialOrd + CheckedAdd + CheckedSub + One,
+{
+    /// Creates an iterator over the pattern.
+    #[inline]
+    pub fn iter(&self) -> RangeStep<T> {
+        try_range_step_bounds(self.start..=self.stop, self.step).unwrap_or_else(|_| RangeStep::empty(false))
+    }
+}
+
+impl<T> IntoIterator for StepPattern<T>
+where
+    T: Copy + Debug + TryToByAdd + TryFromByAdd + Bounded + PartialOrd + CheckedAdd + CheckedSub + One,
+{
+    type Item = T;
+    type IntoIter = RangeStep<T>;
+
+    #[inline]
+    fn into_iter(self) -> RangeStep<T> {
+        self.iter()
+    }
+}
+
+/// Formats the pattern in the syntax of its [`FromStr`](std::str::FromStr) implementation.
+impl<T: Display> Display for StepPattern<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}/{}", self.start, self.stop, self.step)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{range_step_idx, RangeStepIdx};
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+use std::vec::IntoIter;
+
+/// Implements removing range methods (start, stop, step) for a vector.
+///
+/// The remaining elements keep their order, both methods compact the vector in place in a single pass.
+///
+/// # Examples
+///
+///```
+/// use iter_cyclic::RangeStepRemove;
+///
+/// // Packed 3-byte pixels with a padding byte, strips the padding.
+/// let mut buf: Vec<u8> = vec![1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9, 0];
+/// assert_eq!(buf.range_step_drain(3, 3, 4).len(), 3);
+/// assert_eq!(buf, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///
+/// // Keeps only the first byte of each pixel.
+/// buf.range_step_retain(0, 0, 3);
+/// assert_eq!(buf, [1, 4, 7]);
+///
+///```
+pub trait RangeStepRemove<T> {
+    /// Removes the elements in the range (start, stop, step) and returns them in an iterator.
+    ///
+    /// The elements are removed even if the iterator is not consumed.
+    fn range_step_drain(&mut self, start: usize, stop: usize, step: usize) -> RangeStepDrain<T>;
+
+    /// Keeps only the elements in the range (start, stop, step).
+    fn range_step_retain(&mut self, start: usize, stop: usize, step: usize);
+}
+
+impl<T> RangeStepRemove<T> for Vec<T> {
+    #[inline]
+    fn range_step_drain(&mut self, start: usize, stop: usize, step: usize) -> RangeStepDrain<T> {
+        let mut selected = Selected::new(range_step_idx(start, stop, step, self.len()));
+        // The removed elements gather between the kept and the unread ones,
+        // each kept element is swapped with the first of them, which moves to the back.
+        let mut ranks = VecDeque::new();
+        let mut kept = 0;
+        for read in 0..self.len() {
+            if selected.next_is_selected() {
+                ranks.push_back(read - kept);
+            } else {
+                self.swap(kept, read);
+                kept += 1;
+                if let Some(rank) = ranks.pop_front() {
+                    ranks.push_back(rank);
+                }
+            }
+        }
+        let mut removed = self.split_off(kept);
+        // Puts each removed element at its rank, every swap settles one of them.
+        let mut ranks = Vec::from(ranks);
+        for pos in 0..removed.len() {
+            while ranks[pos] != pos {
+                let rank = ranks[pos];
+                removed.swap(pos, rank);
+                ranks.swap(pos, rank);
+            }
+        }
+        RangeStepDrain {
+            removed: removed.into_iter(),
+        }
+    }
+
+    #[inline]
+    fn range_step_retain(&mut self, start: usize, stop: usize, step: usize) {
+        let mut selected = Selected::new(range_step_idx(start, stop, step, self.len()));
+        self.retain(|_| selected.next_is_selected());
+    }
+}
+
+/// Checks the indices of a vector one by one against the ascending indices of a pattern.
+struct Selected {
+    idx: RangeStepIdx,
+    next: Option<usize>,
+    current: usize,
+}
+
+impl Selected {
+    #[inline]
+    fn new(mut idx: RangeStepIdx) -> Self {
+        Selected {
+            next: idx.next(),
+            idx,
+            current: 0,
+        }
+    }
+
+    /// Returns `true` if the next index of the vector is in the pattern.
+    #[inline]
+    fn next_is_selected(&mut self) -> bool {
+        let selected = self.next == Some(self.current);
+        if selected {
+            self.next = self.idx.next();
+        }
+        self.current += 1;
+        selected
+    }
+}
+
+/// An iterator over the elements removed from a vector by the range (start, stop, step).
+///
+/// See the [`RangeStepRemove::range_step_drain()`] method.
+#[derive(Clone, Debug)]
+pub struct RangeStepDrain<T> {
+    removed: IntoIter<T>,
+}
+
+impl<T> Iterator for RangeStepDrain<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.removed.next()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.removed.nth(n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.removed.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeStepDrain<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.removed.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.removed.nth_back(n)
+    }
+}
+
+impl<T> ExactSizeIterator for RangeStepDrain<T> {}
+
+impl<T> FusedIterator for RangeStepDrain<T> {}
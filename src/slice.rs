@@ -0,0 +1,277 @@
+use crate::{deque_elem, range_step_idx, RangeStepIdx};
+use std::collections::VecDeque;
+use std::iter::{Chain, FusedIterator};
+use std::slice::IterMut;
+
+/// Implements borrowing range methods (start, stop, step) for a slice and a deque,
+/// the elements do not have to be `Copy`.
+///
+/// The slice implementation covers the vectors, the arrays and the boxed slices.
+///
+/// # Examples
+///
+///```
+/// use iter_cyclic::RangeStepSlice;
+///
+/// let mut names: Vec<String> = ["a", "b", "c", "d", "e", "f"].map(String::from).to_vec();
+/// for name in names.range_step_mut(1, 1, 3) {
+///     name.push('!');
+/// }
+/// assert_eq!(names.range_step_ref(0, 1, 3).map(String::as_str).collect::<String>(), "ab!de!");
+///
+/// names.range_step_fill(0, 0, 2, String::from("-"));
+/// assert_eq!(names.range_step_to_vec(0, 1, 2), ["-", "b!", "-", "d", "-", "f"]);
+///
+///```
+pub trait RangeStepSlice<T> {
+    /// Returns an iterator over the references to the elements in the range (start, stop, step).
+    fn range_step_ref(&self, start: usize, stop: usize, step: usize) -> RangeStepRef<'_, T>;
+
+    /// Returns an iterator over the mutable references to the elements in the range (start, stop, step).
+    fn range_step_mut(&mut self, start: usize, stop: usize, step: usize) -> RangeStepMut<'_, T>;
+
+    /// Changes the elements of the range (start, stop, step) to clones of the value of the argument.
+    #[inline]
+    fn range_step_fill(&mut self, start: usize, stop: usize, step: usize, val: T)
+    where
+        T: Clone,
+    {
+        for elem in self.range_step_mut(start, stop, step) {
+            elem.clone_from(&val);
+        }
+    }
+
+    /// Returns a new allocated vector containing clones of the elements in the range (start, stop, step).
+    #[inline]
+    fn range_step_to_vec(&self, start: usize, stop: usize, step: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.range_step_ref(start, stop, step).cloned().collect()
+    }
+}
+
+impl<T> RangeStepSlice<T> for [T] {
+    #[inline]
+    fn range_step_ref(&self, start: usize, stop: usize, step: usize) -> RangeStepRef<'_, T> {
+        RangeStepRef::new(self, &[], range_step_idx(start, stop, step, self.len()))
+    }
+
+    #[inline]
+    fn range_step_mut(&mut self, start: usize, stop: usize, step: usize) -> RangeStepMut<'_, T> {
+        let idx = range_step_idx(start, stop, step, self.len());
+        RangeStepMut::new(self, &mut [], idx)
+    }
+}
+
+impl<T> RangeStepSlice<T> for VecDeque<T> {
+    #[inline]
+    fn range_step_ref(&self, start: usize, stop: usize, step: usize) -> RangeStepRef<'_, T> {
+        let (front, back) = self.as_slices();
+        RangeStepRef::new(front, back, range_step_idx(start, stop, step, self.len()))
+    }
+
+    #[inline]
+    fn range_step_mut(&mut self, start: usize, stop: usize, step: usize) -> RangeStepMut<'_, T> {
+        let idx = range_step_idx(start, stop, step, self.len());
+        let (front, back) = self.as_mut_slices();
+        RangeStepMut::new(front, back, idx)
+    }
+}
+
+/// An iterator over the references to the elements in the range (start, stop, step).
+///
+/// See the [`RangeStepSlice::range_step_ref()`] method.
+#[derive(Debug)]
+pub struct RangeStepRef<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    idx: RangeStepIdx,
+}
+
+impl<'a, T> RangeStepRef<'a, T> {
+    #[inline]
+    fn new(front: &'a [T], back: &'a [T], idx: RangeStepIdx) -> Self {
+        RangeStepRef { front, back, idx }
+    }
+}
+
+impl<T> Clone for RangeStepRef<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        RangeStepRef::new(self.front, self.back, self.idx)
+    }
+}
+
+impl<'a, T> Iterator for RangeStepRef<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.idx.next().map(|idx| deque_elem(self.front, self.back, idx))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        self.idx.nth(n).map(|idx| deque_elem(self.front, self.back, idx))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.idx.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeStepRef<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.idx.next_back().map(|idx| deque_elem(self.front, self.back, idx))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.idx.nth_back(n).map(|idx| deque_elem(self.front, self.back, idx))
+    }
+}
+
+impl<T> ExactSizeIterator for RangeStepRef<'_, T> {}
+
+impl<T> FusedIterator for RangeStepRef<'_, T> {}
+
+/// An iterator over the mutable references to the elements in the range (start, stop, step).
+///
+/// The references are split off the slice in the order of the indices, so they never alias.
+/// See the [`RangeStepSlice::range_step_mut()`] method.
+#[derive(Debug)]
+pub struct RangeStepMut<'a, T> {
+    elems: Chain<IterMut<'a, T>, IterMut<'a, T>>,
+    idx: RangeStepIdx,
+    /// The index of the next element of `elems` from the front.
+    front: usize,
+    /// The index after the next element of `elems` from the back.
+    back: usize,
+}
+
+impl<'a, T> RangeStepMut<'a, T> {
+    #[inline]
+    fn new(front: &'a mut [T], back: &'a mut [T], idx: RangeStepIdx) -> Self {
+        RangeStepMut {
+            back: front.len() + back.len(),
+            elems: front.iter_mut().chain(back.iter_mut()),
+            idx,
+            front: 0,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RangeStepMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        let idx = self.idx.nth(n)?;
+        let elem = self.elems.nth(idx - self.front);
+        self.front = idx + 1;
+        elem
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.idx.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeStepMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let idx = self.idx.nth_back(n)?;
+        let elem = self.elems.nth_back(self.back - idx - 1);
+        self.back = idx;
+        elem
+    }
+}
+
+impl<T> ExactSizeIterator for RangeStepMut<'_, T> {}
+
+impl<T> FusedIterator for RangeStepMut<'_, T> {}
+
+/// An iterator over the copies of the elements in the range (start, stop, step) of a borrowed slice or deque.
+///
+/// The borrowing counterpart of [`RangeStepVecIter`](crate::RangeStepVecIter).
+/// See the [`RangeStepVec::range_step_copied()`](crate::RangeStepVec::range_step_copied) method.
+///
+/// # Examples
+///
+///```
+/// use iter_cyclic::{RangeStepSliceIter, RangeStepVec};
+///
+/// struct Channel<'a> {
+///     samples: RangeStepSliceIter<'a, i16>,
+/// }
+///
+/// let frames: Vec<i16> = (0..12).collect();
+/// let right = Channel { samples: frames.range_step_copied(1, 1, 2) };
+/// assert_eq!(right.samples.rev().collect::<Vec<_>>(), [11, 9, 7, 5, 3, 1]);
+///
+///```
+#[derive(Clone, Debug)]
+pub struct RangeStepSliceIter<'a, T> {
+    elems: RangeStepRef<'a, T>,
+}
+
+impl<'a, T: Copy> RangeStepSliceIter<'a, T> {
+    #[inline]
+    pub(crate) fn new(elems: RangeStepRef<'a, T>) -> Self {
+        RangeStepSliceIter { elems }
+    }
+}
+
+impl<T: Copy> Iterator for RangeStepSliceIter<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.elems.next().copied()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.elems.nth(n).copied()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elems.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for RangeStepSliceIter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.elems.next_back().copied()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.elems.nth_back(n).copied()
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for RangeStepSliceIter<'_, T> {}
+
+impl<T: Copy> FusedIterator for RangeStepSliceIter<'_, T> {}
@@ -0,0 +1,664 @@
+use crate::{range_step_idx, CyclicError, RangeStepIdx};
+use std::iter::FusedIterator;
+use std::ops::{Index, IndexMut, Range};
+
+/// The offset, shape and strides of a view, checked against the length of the slice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Layout<const N: usize> {
+    offset: usize,
+    shape: [usize; N],
+    strides: [usize; N],
+    len: usize,
+}
+
+impl<const N: usize> Layout<N> {
+    #[inline]
+    fn try_new(offset: usize, shape: [usize; N], strides: [usize; N], slice_len: usize) -> Result<Self, CyclicError> {
+        let out_of_bounds = |last| CyclicError::ViewOutOfBounds { last, len: slice_len };
+        let len = shape
+            .iter()
+            .try_fold(1_usize, |len, &n| len.checked_mul(n))
+            .ok_or(out_of_bounds(usize::MAX))?;
+        if len != 0 {
+            let last = shape
+                .iter()
+                .zip(strides)
+                .try_fold(offset, |last, (&n, stride)| last.checked_add((n - 1).checked_mul(stride)?))
+                .ok_or(out_of_bounds(usize::MAX))?;
+            if last >= slice_len {
+                return Err(out_of_bounds(last));
+            }
+        }
+        Ok(Layout {
+            offset,
+            shape,
+            strides,
+            len,
+        })
+    }
+
+    #[inline]
+    fn get(&self, coords: [usize; N]) -> Option<usize> {
+        (0..N).try_fold(self.offset, |index, axis| {
+            (coords[axis] < self.shape[axis]).then(|| index + coords[axis] * self.strides[axis])
+        })
+    }
+
+    /// Returns the index in the slice of the element at the coordinates, panics if they are outside of the shape.
+    #[inline]
+    fn index(&self, coords: [usize; N]) -> usize {
+        self.get(coords).unwrap_or_else(|| {
+            let axis = (0..N).find(|&axis| coords[axis] >= self.shape[axis]).unwrap_or_default();
+            panic!(
+                "index {} is out of bounds of axis {axis} with length {}",
+                coords[axis], self.shape[axis]
+            )
+        })
+    }
+
+    /// Returns the number of the outer axes and the indices of a plane of the inner axes relative to its first element.
+    ///
+    /// The last axis is a pattern of [`range_step_idx()`], merged with the previous axis into one block per row
+    /// if its stride is one and the rows do not overlap. A last axis with the stride zero is an outer axis.
+    #[inline]
+    fn plane(&self) -> (usize, RangeStepIdx) {
+        let last = N.wrapping_sub(1);
+        if self.len != 0 && N >= 2 && self.strides[last] == 1 && self.shape[last] <= self.strides[last - 1] {
+            if let Some(end) = self.shape[last - 1].checked_mul(self.strides[last - 1]) {
+                return (N - 2, range_step_idx(0, self.shape[last] - 1, self.strides[last - 1], end));
+            }
+        }
+        if self.len != 0 && N >= 1 && self.strides[last] != 0 {
+            if let Some(end) = self.shape[last].checked_mul(self.strides[last]) {
+                return (N - 1, range_step_idx(0, 0, self.strides[last], end));
+            }
+        }
+        (N, range_step_idx(0, 0, 1, 1))
+    }
+
+    /// Returns the index in the slice of the first element of the plane at the position over the outer axes.
+    #[inline]
+    fn plane_start(&self, axes: usize, position: usize) -> usize {
+        let mut rest = position;
+        (0..axes).rev().fold(self.offset, |index, axis| {
+            let coord = rest % self.shape[axis];
+            rest /= self.shape[axis];
+            index + coord * self.strides[axis]
+        })
+    }
+
+    /// Returns the layout of the sub-view, panics if a range is outside of the shape.
+    #[inline]
+    fn slice(&self, ranges: [Range<usize>; N]) -> Self {
+        for (axis, range) in ranges.iter().enumerate() {
+            if range.start > range.end || range.end > self.shape[axis] {
+                panic!(
+                    "range {range:?} is out of bounds of axis {axis} with length {}",
+                    self.shape[axis]
+                );
+            }
+        }
+        let shape = ranges.clone().map(|range| range.len());
+        let len = shape.iter().product();
+        let offset = if len == 0 {
+            self.offset
+        } else {
+            self.index(ranges.map(|range| range.start))
+        };
+        Layout {
+            offset,
+            shape,
+            strides: self.strides,
+            len,
+        }
+    }
+}
+
+/// A read-only view of the elements of a slice at an offset, with a shape and a stride per axis.
+///
+/// The element at the coordinates `[i0, i1, ...]` is `slice[offset + i0 * stride0 + i1 * stride1 + ...]`.
+/// The last axis changes the fastest in the logical order, as in the row-major arrays.
+/// The strides may overlap, then an element of the slice appears at several coordinates.
+///
+/// A 2D view at the offset zero with the shape `[rows, cols]` and the strides `[step, 1]`
+/// has the indices of [`range_step_idx(0, cols - 1, step, rows * step)`](crate::range_step_idx),
+/// each row is one block.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::StridedView;
+///
+/// // A 4x5 image stored by rows, the 2x3 region at the row 1 and the column 2.
+/// let image: Vec<u8> = (0..20).collect();
+/// let region = StridedView::new(&image, 7, [2, 3], [5, 1]);
+/// assert_eq!(region.iter().copied().collect::<Vec<_>>(), [7, 8, 9, 12, 13, 14]);
+/// assert_eq!(region[[1, 2]], 14);
+///
+/// // The transposed image.
+/// let transposed = StridedView::new(&image, 0, [5, 4], [1, 5]);
+/// assert_eq!(transposed[[4, 1]], 9);
+///
+/// ```
+#[derive(Debug)]
+pub struct StridedView<'a, T, const N: usize> {
+    data: &'a [T],
+    layout: Layout<N>,
+}
+
+impl<T, const N: usize> Clone for StridedView<'_, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for StridedView<'_, T, N> {}
+
+impl<'a, T, const N: usize> StridedView<'a, T, N> {
+    /// Creates a view of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element of the view is outside of the slice,
+    /// see [`try_new()`](Self::try_new) for the fallible version.
+    #[inline]
+    pub fn new(data: &'a [T], offset: usize, shape: [usize; N], strides: [usize; N]) -> Self {
+        Self::try_new(data, offset, shape, strides).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Creates a view of the slice, or an error if an element of the view is outside of the slice.
+    ///
+    /// A view without elements is always valid.
+    ///
+    /// # Errors
+    ///
+    /// [`CyclicError::ViewOutOfBounds`] if the last element of the view is outside of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{CyclicError, StridedView};
+    ///
+    /// let data = [0; 20];
+    /// assert!(StridedView::try_new(&data, 7, [2, 3], [5, 1]).is_ok());
+    ///
+    /// let err = StridedView::try_new(&data, 7, [3, 4], [5, 1]).unwrap_err();
+    /// assert_eq!(err, CyclicError::ViewOutOfBounds { last: 20, len: 20 });
+    ///
+    /// ```
+    #[inline]
+    pub fn try_new(data: &'a [T], offset: usize, shape: [usize; N], strides: [usize; N]) -> Result<Self, CyclicError> {
+        Ok(StridedView {
+            layout: Layout::try_new(offset, shape, strides, data.len())?,
+            data,
+        })
+    }
+
+    /// Returns the number of elements along each axis.
+    #[inline]
+    pub fn shape(&self) -> [usize; N] {
+        self.layout.shape
+    }
+
+    /// Returns the distance in the slice between the neighboring elements along each axis.
+    #[inline]
+    pub fn strides(&self) -> [usize; N] {
+        self.layout.strides
+    }
+
+    /// Returns the index in the slice of the first element.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    /// Returns the number of elements of the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.layout.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.layout.len == 0
+    }
+
+    /// Returns the element at the coordinates, `None` if they are outside of the shape.
+    #[inline]
+    pub fn get(&self, coords: [usize; N]) -> Option<&'a T> {
+        self.layout.get(coords).map(|index| &self.data[index])
+    }
+
+    /// Returns the view of the ranges of the coordinates, the strides are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is outside of the shape.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::StridedView;
+    ///
+    /// let image: Vec<u8> = (0..20).collect();
+    /// let view = StridedView::new(&image, 0, [4, 5], [5, 1]);
+    /// let region = view.slice([1..3, 2..5]);
+    /// assert_eq!(region.offset(), 7);
+    /// assert_eq!(region.iter().copied().collect::<Vec<_>>(), [7, 8, 9, 12, 13, 14]);
+    ///
+    /// ```
+    #[inline]
+    pub fn slice(&self, ranges: [Range<usize>; N]) -> StridedView<'a, T, N> {
+        StridedView {
+            data: self.data,
+            layout: self.layout.slice(ranges),
+        }
+    }
+
+    /// Returns an iterator over the indices in the slice, in the logical order.
+    #[inline]
+    pub fn indices(&self) -> StridedIdx<N> {
+        StridedIdx::new(self.layout)
+    }
+
+    /// Returns an iterator over the elements, in the logical order.
+    #[inline]
+    pub fn iter(&self) -> StridedIter<'a, T, N> {
+        StridedIter {
+            data: self.data,
+            indices: self.indices(),
+        }
+    }
+
+    /// Returns a new allocated vector containing the elements in the logical order.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for StridedView<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, coords: [usize; N]) -> &T {
+        &self.data[self.layout.index(coords)]
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for StridedView<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = StridedIter<'a, T, N>;
+
+    #[inline]
+    fn into_iter(self) -> StridedIter<'a, T, N> {
+        self.iter()
+    }
+}
+
+/// A mutable view of the elements of a slice at an offset, with a shape and a stride per axis.
+///
+/// See [`StridedView`] for the layout.
+/// If the strides overlap, a write through one coordinate is seen through the others.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::StridedViewMut;
+///
+/// let mut image = vec![0_u8; 20];
+/// let mut region = StridedViewMut::new(&mut image, 7, [2, 3], [5, 1]);
+/// region.fill(1);
+/// region[[0, 0]] = 2;
+/// assert_eq!(image[5..15], [0, 0, 2, 1, 1, 0, 0, 1, 1, 1]);
+///
+/// ```
+#[derive(Debug)]
+pub struct StridedViewMut<'a, T, const N: usize> {
+    data: &'a mut [T],
+    layout: Layout<N>,
+}
+
+impl<'a, T, const N: usize> StridedViewMut<'a, T, N> {
+    /// Creates a mutable view of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element of the view is outside of the slice,
+    /// see [`try_new()`](Self::try_new) for the fallible version.
+    #[inline]
+    pub fn new(data: &'a mut [T], offset: usize, shape: [usize; N], strides: [usize; N]) -> Self {
+        Self::try_new(data, offset, shape, strides).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Creates a mutable view of the slice, or an error if an element of the view is outside of the slice.
+    ///
+    /// # Errors
+    ///
+    /// [`CyclicError::ViewOutOfBounds`] if the last element of the view is outside of the slice.
+    #[inline]
+    pub fn try_new(
+        data: &'a mut [T],
+        offset: usize,
+        shape: [usize; N],
+        strides: [usize; N],
+    ) -> Result<Self, CyclicError> {
+        Ok(StridedViewMut {
+            layout: Layout::try_new(offset, shape, strides, data.len())?,
+            data,
+        })
+    }
+
+    /// Returns the number of elements along each axis.
+    #[inline]
+    pub fn shape(&self) -> [usize; N] {
+        self.layout.shape
+    }
+
+    /// Returns the distance in the slice between the neighboring elements along each axis.
+    #[inline]
+    pub fn strides(&self) -> [usize; N] {
+        self.layout.strides
+    }
+
+    /// Returns the index in the slice of the first element.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    /// Returns the number of elements of the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.layout.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.layout.len == 0
+    }
+
+    /// Returns a read-only view of the same elements.
+    #[inline]
+    pub fn as_view(&self) -> StridedView<'_, T, N> {
+        StridedView {
+            data: self.data,
+            layout: self.layout,
+        }
+    }
+
+    /// Returns the element at the coordinates, `None` if they are outside of the shape.
+    #[inline]
+    pub fn get(&self, coords: [usize; N]) -> Option<&T> {
+        self.layout.get(coords).map(|index| &self.data[index])
+    }
+
+    /// Returns the mutable element at the coordinates, `None` if they are outside of the shape.
+    #[inline]
+    pub fn get_mut(&mut self, coords: [usize; N]) -> Option<&mut T> {
+        self.layout.get(coords).map(|index| &mut self.data[index])
+    }
+
+    /// Returns the mutable view of the ranges of the coordinates, the strides are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is outside of the shape.
+    #[inline]
+    pub fn slice_mut(&mut self, ranges: [Range<usize>; N]) -> StridedViewMut<'_, T, N> {
+        StridedViewMut {
+            layout: self.layout.slice(ranges),
+            data: self.data,
+        }
+    }
+
+    /// Returns an iterator over the indices in the slice, in the logical order.
+    #[inline]
+    pub fn indices(&self) -> StridedIdx<N> {
+        StridedIdx::new(self.layout)
+    }
+
+    /// Returns an iterator over the elements, in the logical order.
+    #[inline]
+    pub fn iter(&self) -> StridedIter<'_, T, N> {
+        self.as_view().iter()
+    }
+
+    /// Calls the closure on each mutable element, in the logical order.
+    ///
+    /// An element at several coordinates is visited several times.
+    #[inline]
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&mut T)) {
+        for index in StridedIdx::new(self.layout) {
+            f(&mut self.data[index]);
+        }
+    }
+
+    /// Sets all elements to the value.
+    #[inline]
+    pub fn fill(&mut self, val: T)
+    where
+        T: Clone,
+    {
+        self.for_each_mut(|elem| elem.clone_from(&val));
+    }
+
+    /// Copies the elements of the other view of the same shape, in the logical order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes are different.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::{StridedView, StridedViewMut};
+    ///
+    /// let src: Vec<u8> = (0..6).collect();
+    /// let mut dst = vec![0_u8; 6];
+    /// // Copies the 2x3 matrix into its transposition.
+    /// StridedViewMut::new(&mut dst, 0, [2, 3], [1, 2]).copy_from(&StridedView::new(&src, 0, [2, 3], [3, 1]));
+    /// assert_eq!(dst, [0, 3, 1, 4, 2, 5]);
+    ///
+    /// ```
+    #[inline]
+    pub fn copy_from(&mut self, src: &StridedView<'_, T, N>)
+    where
+        T: Clone,
+    {
+        if self.layout.shape != src.layout.shape {
+            panic!(
+                "source shape {:?} does not match destination shape {:?}",
+                src.layout.shape, self.layout.shape
+            );
+        }
+        for (index, val) in StridedIdx::new(self.layout).zip(src.iter()) {
+            self.data[index].clone_from(val);
+        }
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for StridedViewMut<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, coords: [usize; N]) -> &T {
+        &self.data[self.layout.index(coords)]
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for StridedViewMut<'_, T, N> {
+    #[inline]
+    fn index_mut(&mut self, coords: [usize; N]) -> &mut T {
+        &mut self.data[self.layout.index(coords)]
+    }
+}
+
+/// An iterator over the indices in the slice of the elements of a strided view, in the logical order.
+///
+/// The planes of the inner axes are iterated with a [`RangeStepIdx`], see the [`StridedView::indices()`] method.
+#[derive(Clone, Copy, Debug)]
+pub struct StridedIdx<const N: usize> {
+    layout: Layout<N>,
+    axes: usize,
+    plane: RangeStepIdx,
+    planes_front: usize,
+    planes_back: usize,
+    front: Option<(usize, RangeStepIdx)>,
+    back: Option<(usize, RangeStepIdx)>,
+}
+
+impl<const N: usize> StridedIdx<N> {
+    #[inline]
+    fn new(layout: Layout<N>) -> Self {
+        let (axes, plane) = layout.plane();
+        StridedIdx {
+            planes_front: 0,
+            planes_back: layout.len / plane.len(),
+            front: None,
+            back: None,
+            layout,
+            axes,
+            plane,
+        }
+    }
+
+    /// Returns the plane at the position over the outer axes and the index in the slice of its first element.
+    #[inline]
+    fn open(&self, position: usize) -> (usize, RangeStepIdx) {
+        (self.layout.plane_start(self.axes, position), self.plane)
+    }
+}
+
+impl<const N: usize> Iterator for StridedIdx<N> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<usize> {
+        if let Some((start, plane)) = &mut self.front {
+            if n < plane.len() {
+                return plane.nth(n).map(|idx| *start + idx);
+            }
+            n -= plane.len();
+            self.front = None;
+        }
+        let (planes, plane_len) = (self.planes_back - self.planes_front, self.plane.len());
+        if n / plane_len < planes {
+            self.planes_front += n / plane_len + 1;
+            let (start, mut plane) = self.open(self.planes_front - 1);
+            let idx = plane.nth(n % plane_len).map(|idx| start + idx);
+            self.front = Some((start, plane));
+            return idx;
+        }
+        n -= planes * plane_len;
+        self.planes_front = self.planes_back;
+        let (start, plane) = self.back.as_mut()?;
+        plane.nth(n).map(|idx| *start + idx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let open = |plane: &Option<(usize, RangeStepIdx)>| plane.map_or(0, |(_, plane)| plane.len());
+        let len = open(&self.front) + (self.planes_back - self.planes_front) * self.plane.len() + open(&self.back);
+        (len, Some(len))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for StridedIdx<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        self.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, mut n: usize) -> Option<usize> {
+        if let Some((start, plane)) = &mut self.back {
+            if n < plane.len() {
+                return plane.nth_back(n).map(|idx| *start + idx);
+            }
+            n -= plane.len();
+            self.back = None;
+        }
+        let (planes, plane_len) = (self.planes_back - self.planes_front, self.plane.len());
+        if n / plane_len < planes {
+            self.planes_back -= n / plane_len + 1;
+            let (start, mut plane) = self.open(self.planes_back);
+            let idx = plane.nth_back(n % plane_len).map(|idx| start + idx);
+            self.back = Some((start, plane));
+            return idx;
+        }
+        n -= planes * plane_len;
+        self.planes_back = self.planes_front;
+        let (start, plane) = self.front.as_mut()?;
+        plane.nth_back(n).map(|idx| *start + idx)
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for StridedIdx<N> {}
+
+impl<const N: usize> FusedIterator for StridedIdx<N> {}
+
+/// An iterator over the elements of a strided view, in the logical order.
+///
+/// See the [`StridedView::iter()`] method.
+#[derive(Debug)]
+pub struct StridedIter<'a, T, const N: usize> {
+    data: &'a [T],
+    indices: StridedIdx<N>,
+}
+
+impl<T, const N: usize> Clone for StridedIter<'_, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        StridedIter {
+            data: self.data,
+            indices: self.indices,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for StridedIter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.indices.next().map(|index| &self.data[index])
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        self.indices.nth(n).map(|index| &self.data[index])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for StridedIter<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.indices.next_back().map(|index| &self.data[index])
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<&'a T> {
+        self.indices.nth_back(n).map(|index| &self.data[index])
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for StridedIter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for StridedIter<'_, T, N> {}
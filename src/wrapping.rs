@@ -0,0 +1,184 @@
+use crate::blocks::Blocks;
+use crate::gaps::Gaps;
+use crate::overflow::Span;
+use num_convert::{TryFromByAdd, TryToByAdd};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// An iterator that continues the blocks of a cyclic iterator modulo the range of the type.
+///
+/// Without a limit the iterator never ends, its size hint is `(usize::MAX, None)`.
+/// The [`blocks()`](Self::blocks), [`until()`](Self::until) and [`until_partial()`](Self::until_partial)
+/// limits end it, the size hint is exact then.
+/// The iterator is not double-ended and has no length, since its end may not exist.
+/// A skip of zero outputs a single block, as under the other policies.
+///
+/// Created by the [`RangeSkip::wrapping()`](crate::RangeSkip::wrapping)
+/// and [`RangeStep::wrapping()`](crate::RangeStep::wrapping) methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use iter_cyclic::range_step;
+///
+/// let iter = range_step(0_u8, 1, 100).wrapping();
+/// assert_eq!(iter.size_hint(), (usize::MAX, None));
+/// assert_eq!(iter.take(8).collect::<Vec<_>>(), [0, 1, 100, 101, 200, 201, 44, 45]);
+///
+/// let iter = range_step(0_u8, 1, 100).wrapping().blocks(4);
+/// assert_eq!(iter.size_hint(), (8, Some(8)));
+/// assert_eq!(iter.last(), Some(45));
+///
+/// ```
+#[derive(Clone, Debug)]
+pub struct Wrapping<T> {
+    span: Span,
+    marker: PhantomData<T>,
+}
+
+impl<T> Wrapping<T> {
+    #[inline]
+    pub(crate) fn new(mut span: Span) -> Self {
+        span.set_wrap();
+        Wrapping {
+            span,
+            marker: PhantomData,
+        }
+    }
+
+    /// Turns the iterator into an iterator over its blocks, one inclusive range per block.
+    ///
+    /// See the [`Blocks`] iterator for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step;
+    ///
+    /// let mut iter = range_step(0_u8, 9, 83).wrapping().blocks(4);
+    /// iter.next();
+    /// let blocks: Vec<_> = iter.into_blocks().collect();
+    /// assert_eq!(blocks, [1..=9, 83..=92, 166..=175, 249..=255, 0..=2]);
+    ///
+    /// ```
+    #[inline]
+    pub fn into_blocks(self) -> Blocks<T> {
+        Blocks::new(self.span)
+    }
+
+    /// Returns an iterator over the values that the pattern of the iterator skips.
+    ///
+    /// The values between the blocks wrap as the blocks, there are no values after the last block.
+    /// See the [`Gaps`] iterator for more information.
+    #[inline]
+    pub fn gaps(&self) -> Gaps<T> {
+        Gaps::new(self.span.gaps())
+    }
+
+    /// Checks whether the pattern of the iterator outputs the value.
+    ///
+    /// The check is in constant time, only the blocks before the values wrap are searched.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool
+    where
+        T: TryToByAdd,
+    {
+        self.position_of(value).is_some()
+    }
+
+    /// Returns the position of the value in the blocks before the values wrap, counted from zero.
+    ///
+    /// See the [`contains()`](Self::contains) method.
+    #[inline]
+    pub fn position_of(&self, value: T) -> Option<usize>
+    where
+        T: TryToByAdd,
+    {
+        let idx = self.span.position_of(value.try_into_u128()?)?;
+        usize::try_from(idx).ok()
+    }
+
+    /// Returns the value at the position in the pattern of the iterator, counted from zero.
+    ///
+    /// The same as `nth(index)` on the fresh iterator, in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iter_cyclic::range_step;
+    ///
+    /// let iter = range_step(0_u8, 0, 100).wrapping();
+    /// assert_eq!(iter.value_at(3), Some(44));
+    /// assert_eq!(iter.position_of(200), Some(2));
+    /// assert_eq!(iter.position_of(44), None);
+    ///
+    /// ```
+    #[inline]
+    pub fn value_at(&self, index: usize) -> Option<T>
+    where
+        T: TryFromByAdd,
+    {
+        self.span.offset_at(index as u128).and_then(T::try_from_u128)
+    }
+
+    /// Limits the iterator to the first `n` blocks.
+    #[inline]
+    #[must_use]
+    pub fn blocks(mut self, n: usize) -> Self {
+        self.span.limit_blocks(n as u128);
+        self
+    }
+
+    /// Limits the iterator to the whole blocks that do not go past the `limit` value before the values wrap.
+    ///
+    /// See the [`RangeSkip::until()`](crate::RangeSkip::until) method.
+    #[inline]
+    #[must_use]
+    pub fn until(mut self, limit: T) -> Self
+    where
+        T: TryToByAdd,
+    {
+        if let Some(limit) = limit.try_into_u128() {
+            self.span.limit_offset(limit, false);
+        }
+        self
+    }
+
+    /// Limits the iterator to the values that do not go past the `limit` value before the values wrap,
+    /// the last block is cut at the limit.
+    #[inline]
+    #[must_use]
+    pub fn until_partial(mut self, limit: T) -> Self
+    where
+        T: TryToByAdd,
+    {
+        if let Some(limit) = limit.try_into_u128() {
+            self.span.limit_offset(limit, true);
+        }
+        self
+    }
+}
+
+impl<T> Iterator for Wrapping<T>
+where
+    T: TryFromByAdd,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.span.next().and_then(T::try_from_u128)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.span.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.span.nth(n).and_then(T::try_from_u128)
+    }
+}
+
+impl<T> FusedIterator for Wrapping<T> where T: TryFromByAdd {}
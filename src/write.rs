@@ -0,0 +1,52 @@
+use crate::RangeStepIdx;
+
+/// Which side of a write ran out first.
+///
+/// See the [`RangeStepVec::range_step_write()`](crate::RangeStepVec::range_step_write) method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Exhausted {
+    /// Every element of the pattern was written, the source may still have values.
+    Pattern,
+    /// The source ran out before the end of the pattern.
+    Source,
+}
+
+/// The result of writing a source iterator into the range (start, stop, step).
+///
+/// See the [`RangeStepVec::range_step_write()`](crate::RangeStepVec::range_step_write) method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeStepWrite<I> {
+    /// The number of written elements.
+    pub written: usize,
+    /// Which side ran out first.
+    pub exhausted: Exhausted,
+    /// The unconsumed rest of the source.
+    pub rest: I,
+}
+
+/// Writes the values of the source at the indices until one of them runs out.
+#[inline]
+pub(crate) fn write_values<T, I>(idx: RangeStepIdx, mut iter: I, mut set: impl FnMut(usize, T)) -> RangeStepWrite<I>
+where
+    I: Iterator<Item = T>,
+{
+    let mut written = 0;
+    for idx in idx {
+        match iter.next() {
+            Some(val) => set(idx, val),
+            None => {
+                return RangeStepWrite {
+                    written,
+                    exhausted: Exhausted::Source,
+                    rest: iter,
+                }
+            }
+        }
+        written += 1;
+    }
+    RangeStepWrite {
+        written,
+        exhausted: Exhausted::Pattern,
+        rest: iter,
+    }
+}
@@ -0,0 +1,119 @@
+use iter_cyclic::{PatternSet, StridePattern};
+
+fn indices(set: PatternSet) -> Vec<usize> {
+    set.into_iter().collect()
+}
+
+#[test]
+fn intersection_pattern() {
+    let a = StridePattern::new(0, 0, 2, 24);
+    let b = StridePattern::new(0, 0, 3, 24);
+    let PatternSet::Pattern(both) = a.intersection(&b) else { panic!("expected a pattern") };
+    assert_eq!(both, StridePattern::new(0, 0, 6, 24));
+    assert_eq!(both.into_iter().collect::<Vec<_>>(), [0, 6, 12, 18]);
+}
+
+#[test]
+fn intersection_cut_at_shorter_end() {
+    let a = StridePattern::new(0, 1, 4, 100);
+    let b = StridePattern::new(0, 3, 8, 19);
+    let PatternSet::Pattern(both) = a.intersection(&b) else { panic!("expected a pattern") };
+    assert_eq!(both, StridePattern::new(0, 1, 8, 16));
+
+    let c = StridePattern::new(0, 3, 8, 100);
+    let d = StridePattern::new(0, 1, 2, 2);
+    let both = c.intersection(&d);
+    assert!(matches!(both, PatternSet::Merged(_)));
+    assert_eq!(indices(both), [0, 1]);
+}
+
+#[test]
+fn union_merged() {
+    let a = StridePattern::new(0, 0, 2, 12);
+    let b = StridePattern::new(0, 0, 3, 12);
+    let union = a.union(&b);
+    assert!(matches!(union, PatternSet::Merged(_)));
+    assert_eq!(indices(union), [0, 2, 3, 4, 6, 8, 9, 10]);
+}
+
+#[test]
+fn union_of_contained_pattern() {
+    let a = StridePattern::new(0, 0, 2, 1000);
+    let b = StridePattern::new(0, 0, 4, 10);
+    let PatternSet::Pattern(any) = a.union(&b) else { panic!("expected a pattern") };
+    assert_eq!(any, a);
+}
+
+#[test]
+fn difference() {
+    let a = StridePattern::new(0, 3, 4, 16);
+    let b = StridePattern::new(1, 2, 4, 16);
+    assert_eq!(indices(a.difference(&b)), [0, 3, 4, 7, 8, 11, 12, 15]);
+
+    let c = StridePattern::new(0, 1, 4, 8);
+    assert_eq!(indices(a.difference(&c)), [2, 3, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    let PatternSet::Pattern(rest) = c.difference(&a) else { panic!("expected a pattern") };
+    assert_eq!(rest.into_iter().count(), 0);
+}
+
+#[test]
+fn invalid_is_empty() {
+    let a = StridePattern::new(0, 1, 4, 16);
+    let invalid = StridePattern::new(3, 1, 4, 16);
+    assert_eq!(indices(a.intersection(&invalid)), []);
+    assert_eq!(indices(a.union(&invalid)), indices(PatternSet::Pattern(a)));
+    assert_eq!(indices(invalid.union(&a)), indices(PatternSet::Pattern(a)));
+    assert_eq!(indices(a.difference(&invalid)), indices(PatternSet::Pattern(a)));
+}
+
+#[test]
+fn zero_step_is_empty() {
+    let a = StridePattern::new(0, 1, 4, 24);
+    let zero = StridePattern::new(0, 0, 0, 24);
+    let parsed: StridePattern = "0..=0/0".parse().unwrap();
+    assert_eq!(indices(a.intersection(&zero)), []);
+    assert_eq!(indices(parsed.intersection(&a)), []);
+}
+
+#[test]
+fn zero_step_union() {
+    let a = StridePattern::new(0, 1, 4, 24);
+    let zero = StridePattern::new(0, 0, 0, 24);
+    assert_eq!(indices(a.union(&zero)), indices(PatternSet::Pattern(a)));
+    assert_eq!(indices(zero.union(&zero)), []);
+}
+
+#[test]
+fn zero_step_difference() {
+    let a = StridePattern::new(0, 1, 4, 24);
+    let zero = StridePattern::new(0, 0, 0, 24);
+    assert_eq!(indices(a.difference(&zero)), indices(PatternSet::Pattern(a)));
+    assert_eq!(indices(zero.difference(&a)), []);
+}
+
+#[test]
+fn unbounded_patterns() {
+    let a: StridePattern = "0..=1/4".parse().unwrap();
+    let b: StridePattern = "0..=0/6".parse().unwrap();
+    let PatternSet::Pattern(both) = a.intersection(&b) else { panic!("expected a pattern") };
+    assert_eq!(both.to_string(), format!("0..=0/12 len {}", usize::MAX / 12 * 12));
+    assert_eq!(a.union(&b).into_iter().take(6).collect::<Vec<_>>(), [0, 1, 4, 5, 6, 8]);
+}
+
+#[test]
+fn intersection_of_large_steps() {
+    let a = StridePattern::new(0, 0, 2, usize::MAX);
+    let b = StridePattern::new(0, 0, 1_000_000_007, usize::MAX);
+    assert_eq!(a.intersection(&b), PatternSet::Pattern(StridePattern::new(0, 0, 2_000_000_014, usize::MAX / 2_000_000_014 * 2_000_000_014)));
+    let c = StridePattern::new(1, 1, 1_000_000_007, usize::MAX);
+    let PatternSet::Pattern(odd) = a.intersection(&c) else { panic!() };
+    assert_eq!(odd.iter().take(2).collect::<Vec<_>>(), [1_000_000_008, 3_000_000_022]);
+}
+
+#[test]
+fn union_of_large_steps_is_merged() {
+    let a = StridePattern::new(0, 0, 2, usize::MAX);
+    let b = StridePattern::new(1, 1, 1_000_000_007, usize::MAX);
+    let PatternSet::Merged(any) = a.union(&b) else { panic!() };
+    assert_eq!(any.iter().take(4).collect::<Vec<_>>(), [0, 1, 2, 4]);
+}
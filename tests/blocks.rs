@@ -0,0 +1,65 @@
+use iter_cyclic::{range_skip, range_skip_bounds, range_step, range_step_desc, range_step_idx, OverflowPolicy};
+
+#[test]
+fn skip_blocks() {
+    assert_eq!(range_skip(0_u8..5, 200).into_blocks().collect::<Vec<_>>(), [0..=5, 206..=211]);
+    assert_eq!(range_skip(0_u8..5, 0).into_blocks().collect::<Vec<_>>(), [0..=5]);
+}
+
+#[test]
+fn skip_blocks_partially_consumed() {
+    let mut iter = range_skip(0_u8..5, 200);
+    iter.nth(2);
+    iter.next_back();
+    assert_eq!(iter.into_blocks().collect::<Vec<_>>(), [3..=5, 206..=210]);
+}
+
+#[test]
+fn skip_blocks_saturate() {
+    let blocks = range_skip_bounds(250_u8..=252, 1).overflow(OverflowPolicy::Saturate).into_blocks();
+    assert_eq!(blocks.size_hint(), (2, Some(2)));
+    assert_eq!(blocks.collect::<Vec<_>>(), [250..=252, 254..=255]);
+}
+
+#[test]
+fn skip_blocks_full_range() {
+    assert_eq!(range_skip(i8::MIN..i8::MAX, 0).into_blocks().collect::<Vec<_>>(), [i8::MIN..=i8::MAX]);
+    assert_eq!(range_skip(u128::MIN..u128::MAX, 0).into_blocks().count(), 1);
+}
+
+#[test]
+fn step_blocks() {
+    let blocks = range_step(0_u8, 5, 20).blocks(3).into_blocks();
+    assert_eq!(blocks.size_hint(), (3, Some(3)));
+    assert_eq!(blocks.rev().collect::<Vec<_>>(), [40..=45, 20..=25, 0..=5]);
+}
+
+#[test]
+fn step_blocks_desc() {
+    assert_eq!(
+        range_step_desc(126_i8..=127, 127).into_blocks().collect::<Vec<_>>(),
+        [126..=127, -1..=0]
+    );
+}
+
+#[test]
+fn step_blocks_wrap() {
+    let blocks = range_step(0_u8, 9, 83).wrapping().blocks(5).into_blocks();
+    assert_eq!(blocks.size_hint(), (5, Some(10)));
+    assert_eq!(blocks.skip(3).collect::<Vec<_>>(), [249..=255, 0..=2, 76..=85]);
+}
+
+#[test]
+fn idx_blocks_slices() {
+    let data: Vec<u32> = (0..22).collect();
+    let mut out = Vec::new();
+    for block in range_step_idx(3, 4, 7, data.len()).into_blocks() {
+        out.extend_from_slice(&data[block]);
+    }
+    assert_eq!(out, [3, 4, 10, 11, 17, 18]);
+}
+
+#[test]
+fn idx_blocks_empty() {
+    assert_eq!(range_step_idx(0, 2, 7, 6).into_blocks().next(), None);
+}
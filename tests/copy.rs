@@ -0,0 +1,51 @@
+use iter_cyclic::{copy_range_step, swap_range_step, CyclicError, StridePattern};
+
+#[test]
+fn copy_between_strides() {
+    let src: Vec<u32> = (0..12).collect();
+    let mut dst = vec![0_u32; 12];
+    let count = copy_range_step(&src, StridePattern::new(0, 1, 4, 12), &mut dst, StridePattern::new(1, 1, 2, 12));
+    assert_eq!(count, Ok(6));
+    assert_eq!(dst, [0, 0, 0, 1, 0, 4, 0, 5, 0, 8, 0, 9]);
+}
+
+#[test]
+fn copy_clips_end_to_slice() {
+    let src = ["a", "b", "c"].map(String::from);
+    let mut dst = vec![String::new(); 4];
+    let all = StridePattern::new(0, 0, 1, usize::MAX);
+    assert_eq!(copy_range_step(&src, all, &mut dst, StridePattern::new(0, 0, 1, 3)), Ok(3));
+    assert_eq!(dst, ["a", "b", "c", ""]);
+}
+
+#[test]
+fn copy_mismatch_leaves_destination() {
+    let src = [1, 2, 3, 4];
+    let mut dst = [0; 4];
+    let err = copy_range_step(&src, StridePattern::new(0, 0, 1, 4), &mut dst, StridePattern::new(0, 0, 2, 4));
+    assert_eq!(err, Err(CyclicError::LengthMismatch { src: 4, dst: 2 }));
+    assert_eq!(err.unwrap_err().to_string(), "source pattern has 4 elements, destination pattern has 2");
+    assert_eq!(dst, [0; 4]);
+}
+
+#[test]
+fn copy_invalid_patterns_are_empty() {
+    let mut dst = [0; 4];
+    assert_eq!(copy_range_step(&[1, 2], StridePattern::new(0, 4, 4, 2), &mut dst, StridePattern::new(3, 1, 2, 4)), Ok(0));
+}
+
+#[test]
+fn swap_halves() {
+    let mut vec: Vec<u8> = (0..8).collect();
+    let count = swap_range_step(&mut vec, StridePattern::new(0, 3, 8, 8), StridePattern::new(4, 7, 8, 8));
+    assert_eq!(count, Ok(4));
+    assert_eq!(vec, [4, 5, 6, 7, 0, 1, 2, 3]);
+}
+
+#[test]
+fn swap_mismatch() {
+    let mut vec: Vec<u8> = (0..8).collect();
+    let err = swap_range_step(&mut vec, StridePattern::new(0, 0, 2, 8), StridePattern::new(0, 0, 4, 8));
+    assert_eq!(err, Err(CyclicError::LengthMismatch { src: 4, dst: 2 }));
+    assert_eq!(vec, (0..8).collect::<Vec<_>>());
+}
@@ -0,0 +1,74 @@
+use iter_cyclic::{range_skip_desc, range_step_desc, try_range_skip_desc, try_range_step_desc, CyclicError};
+
+#[test]
+fn skip_desc_u8() {
+    let vec: Vec<u8> = range_skip_desc(98..=100, 7).collect();
+    assert_eq!(&vec[..6], [100, 99, 98, 90, 89, 88]);
+    assert_eq!(&vec[vec.len() - 3..], [10, 9, 8]);
+}
+
+#[test]
+fn skip_desc_i8_stops_at_min() {
+    let vec: Vec<i8> = range_skip_desc(-100..=-99, 10).collect();
+    assert_eq!(vec, [-99, -100, -111, -112, -123, -124]);
+}
+
+#[test]
+fn skip_desc_zero_skip() {
+    assert_eq!(range_skip_desc(1_u8..4, 0).collect::<Vec<_>>(), [3, 2, 1]);
+}
+
+#[test]
+fn skip_desc_rev_and_nth() {
+    assert_eq!(range_skip_desc(4_u8..=5, 1).rev().collect::<Vec<_>>(), [1, 2, 4, 5]);
+    assert_eq!(range_skip_desc(98_u16..=100, 7).nth(4), Some(89));
+}
+
+#[test]
+fn skip_desc_wide_types() {
+    assert_eq!(range_skip_desc(..=u128::MAX, 0).next(), Some(u128::MAX));
+    assert_eq!(range_skip_desc(i128::MIN + 3..=i128::MIN + 4, 1).collect::<Vec<_>>(), [
+        i128::MIN + 4,
+        i128::MIN + 3,
+        i128::MIN + 1,
+        i128::MIN
+    ]);
+}
+
+#[test]
+fn try_skip_desc_single_block() {
+    assert_eq!(
+        try_range_skip_desc(i8::MIN..=i8::MIN + 1, 1).unwrap().collect::<Vec<_>>(),
+        [i8::MIN + 1, i8::MIN]
+    );
+}
+
+#[test]
+fn step_desc_u8() {
+    assert_eq!(
+        range_step_desc(u8::MAX - 1..=u8::MAX, 85).collect::<Vec<_>>(),
+        [255, 254, 170, 169, 85, 84]
+    );
+}
+
+#[test]
+fn step_desc_i8() {
+    assert_eq!(range_step_desc(i8::MAX..=i8::MAX, 86).collect::<Vec<_>>(), [127, 41]);
+}
+
+#[test]
+fn step_desc_invalid_is_empty() {
+    assert_eq!(range_step_desc(0_u8..=255, 20).next(), None);
+}
+
+#[test]
+fn try_step_desc_errors() {
+    assert_eq!(
+        try_range_step_desc(250_u8..=255, 0).unwrap_err(),
+        CyclicError::BlockWiderThanStep { stop: 250, step: 0 }
+    );
+    assert_eq!(
+        try_range_step_desc(250_u8..=255, 300).unwrap_err(),
+        CyclicError::Overflow { start: 250, end: 255, step: 300 }
+    );
+}
@@ -0,0 +1,62 @@
+use iter_cyclic::{range_skip, range_step, range_step_idx, RangeStepIter, RangeStepVec};
+
+#[test]
+fn range_skip_rev() {
+    assert_eq!(
+        range_skip(0_u8..5, 200).rev().collect::<Vec<_>>(),
+        [211, 210, 209, 208, 207, 206, 5, 4, 3, 2, 1, 0]
+    );
+}
+
+#[test]
+fn range_skip_rev_last_block() {
+    assert_eq!(range_skip(0_u8..1, 1).rev().take(4).collect::<Vec<_>>(), [253, 252, 250, 249]);
+}
+
+#[test]
+fn range_skip_rev_full_type() {
+    assert_eq!(range_skip(0..u128::MAX, 0).next_back(), Some(u128::MAX));
+    assert_eq!(range_skip(i128::MIN..i128::MIN, 1).next_back(), Some(i128::MAX - 1));
+}
+
+#[test]
+fn range_skip_meet() {
+    let mut iter = range_skip(0_u8..1, 200);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(203));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(202));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn range_step_rev() {
+    assert_eq!(
+        range_step(i8::MIN, i8::MIN + 1, 85).rev().collect::<Vec<_>>(),
+        [43, 42, -42, -43, -127, -128]
+    );
+}
+
+#[test]
+fn range_step_idx_rev() {
+    assert_eq!(range_step_idx(0, 2, 7, 22).rev().collect::<Vec<_>>(), [16, 15, 14, 9, 8, 7, 2, 1, 0]);
+}
+
+#[test]
+fn range_step_idx_rev_empty() {
+    assert_eq!(range_step_idx(0, 6, 5, 255).next_back(), None);
+}
+
+#[test]
+fn vec_iter_rev() {
+    let vec: Vec<i8> = (-10..24).collect();
+    assert_eq!(vec.range_step_iter(0, 2, 7).rev().collect::<Vec<_>>(), [13, 12, 11, 6, 5, 4, -1, -2, -3, -8, -9, -10]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn vec_map_iter_rev() {
+    let vec: Vec<u8> = (0..12).collect();
+    assert_eq!(RangeStepVec::range_step_iter(&vec, 0, 1, 4).rev().collect::<Vec<_>>(), [9, 8, 5, 4, 1, 0]);
+}
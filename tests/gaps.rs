@@ -0,0 +1,97 @@
+use iter_cyclic::{
+    range_skip, range_skip_bounds, range_skip_desc, range_step, range_step_bounds, range_step_desc, range_step_idx,
+    OverflowPolicy,
+};
+
+#[test]
+fn skip_gaps() {
+    let iter = range_skip(0_u8..5, 200);
+    assert_eq!(iter.gaps().into_blocks().collect::<Vec<_>>(), [6..=205, 212..=255]);
+    assert_eq!(iter.gaps().len(), 244);
+    assert_eq!(iter.gaps().count() + iter.count(), 256);
+}
+
+#[test]
+fn skip_zero_gaps() {
+    assert_eq!(range_skip(3_u8..5, 0).gaps().into_blocks().collect::<Vec<_>>(), [0..=2, 6..=255]);
+    assert_eq!(range_skip(i8::MIN..i8::MAX, 0).gaps().next(), None);
+}
+
+#[test]
+fn skip_desc_gaps() {
+    assert_eq!(
+        range_skip_desc(4_u8..=5, 1).gaps().collect::<Vec<_>>(),
+        (6..=255).rev().chain([3, 0]).collect::<Vec<u8>>()
+    );
+}
+
+#[test]
+fn skip_gaps_follow_limits() {
+    assert_eq!(
+        range_skip(0_u8..1, 10).blocks(2).gaps().into_blocks().collect::<Vec<_>>(),
+        [2..=11, 14..=255]
+    );
+    assert_eq!(
+        range_skip_bounds(0_u8..=5, 120)
+            .overflow(OverflowPolicy::Saturate)
+            .gaps()
+            .into_blocks()
+            .collect::<Vec<_>>(),
+        [6..=125, 132..=251]
+    );
+}
+
+#[test]
+fn skip_gaps_wrap() {
+    let iter = range_skip_bounds(250_u8..=252, 2).wrapping().blocks(3);
+    assert_eq!(iter.gaps().into_blocks().collect::<Vec<_>>(), [0..=249, 253..=254, 2..=3]);
+}
+
+#[test]
+fn skip_gaps_ignore_consumed() {
+    let mut iter = range_skip(0_u8..5, 200);
+    iter.nth(7);
+    assert_eq!(iter.gaps().next(), Some(6));
+}
+
+#[test]
+fn step_gaps() {
+    let gaps: Vec<_> = range_step(i8::MIN + 1, i8::MIN + 2, 100).gaps().into_blocks().collect();
+    assert_eq!(gaps, [-128..=-128, -125..=-28, -25..=127]);
+}
+
+#[test]
+fn idx_gaps() {
+    let gaps: Vec<usize> = range_step_idx(0, 2, 7, 22).gaps().collect();
+    assert_eq!(gaps, [3, 4, 5, 6, 10, 11, 12, 13, 17, 18, 19, 20, 21]);
+    assert_eq!(range_step_idx(0, 2, 7, 22).gaps().next_back(), Some(21));
+}
+
+#[test]
+fn idx_gaps_interleave() {
+    let data: Vec<u32> = (0..20).collect();
+    let iter = range_step_idx(1, 2, 5, data.len());
+    let mut all: Vec<u32> = iter.map(|idx| data[idx]).chain(iter.gaps().map(|idx| data[idx])).collect();
+    all.sort_unstable();
+    assert_eq!(all, data);
+}
+
+#[test]
+fn idx_invalid_gaps() {
+    assert_eq!(range_step_idx(0, 7, 7, 22).gaps().next(), None);
+}
+
+#[test]
+fn empty_and_invalid_gaps() {
+    assert_eq!(range_step(0_u8, 5, 3).gaps().next(), None);
+    assert_eq!(range_skip_bounds(5_i8..5, 3).gaps().next(), None);
+    assert_eq!(range_step_desc(0_u8..=255, 3).gaps().next(), None);
+    assert_eq!(range_step_bounds(0_u16..=20, 20).gaps().next_back(), None);
+    assert_eq!(range_skip_desc(5_i8..5, 3).wrapping().gaps().next(), None);
+}
+
+#[test]
+fn no_blocks_skips_every_value() {
+    let gaps: Vec<_> = range_skip_bounds(0_u8..=5, 10).blocks(0).gaps().into_blocks().collect();
+    assert_eq!(gaps, [0..=255]);
+}
@@ -0,0 +1,68 @@
+use iter_cyclic::{range_skip, range_skip_bounds, range_step, range_step_desc, OverflowPolicy};
+
+#[test]
+fn skip_blocks() {
+    assert_eq!(range_skip(0_u8..5, 200).blocks(0).next(), None);
+    assert_eq!(range_skip(0_u8..5, 200).blocks(1).collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(range_skip(0_u8..5, 200).blocks(10).len(), 12);
+    assert_eq!(range_skip(0_u8..5, 0).blocks(10).len(), 6);
+}
+
+#[test]
+fn skip_blocks_rev() {
+    assert_eq!(
+        range_skip(0_u8..1, 10).blocks(3).rev().collect::<Vec<_>>(),
+        [25, 24, 13, 12, 1, 0]
+    );
+}
+
+#[test]
+fn skip_blocks_wrap_exact_size() {
+    let iter = range_skip_bounds(250_u8..=252, 2).wrapping().blocks(3);
+    assert_eq!(iter.size_hint(), (9, Some(9)));
+    assert_eq!(iter.collect::<Vec<_>>(), [250, 251, 252, 255, 0, 1, 4, 5, 6]);
+}
+
+#[test]
+fn skip_blocks_before_panic() {
+    let iter = range_skip_bounds(250_u8..=252, 2).overflow(OverflowPolicy::Panic).blocks(1);
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+fn skip_until() {
+    assert_eq!(range_skip(0_u8..1, 10).until(25).len(), 6);
+    assert_eq!(range_skip(0_u8..1, 10).until(24).len(), 4);
+    assert_eq!(range_skip(0_u8..1, 10).until_partial(24).len(), 5);
+    assert_eq!(range_skip(10_u8..11, 10).until(5).next(), None);
+    assert_eq!(range_skip(10_u8..11, 10).until_partial(5).next(), None);
+}
+
+#[test]
+fn skip_until_saturate() {
+    let vec: Vec<u8> = range_skip_bounds(0..=5, 120)
+        .overflow(OverflowPolicy::Saturate)
+        .until(u8::MAX)
+        .collect();
+    assert_eq!(vec.len(), 16);
+    assert_eq!(range_skip_bounds(0_u8..=5, 120).wrapping().until(200).count(), 12);
+}
+
+#[test]
+fn limits_combine() {
+    assert_eq!(range_step(0_u8, 5, 20).blocks(2).until(100).len(), 12);
+    assert_eq!(range_step(0_u8, 5, 20).until(100).blocks(7).len(), 30);
+    assert_eq!(range_step(0_u8, 5, 20).blocks(4).until_partial(62).len(), 21);
+}
+
+#[test]
+fn step_until_desc() {
+    assert_eq!(
+        range_step_desc(250_u8..=255, 20).until(230).collect::<Vec<_>>(),
+        [255, 254, 253, 252, 251, 250, 235, 234, 233, 232, 231, 230]
+    );
+    assert_eq!(
+        range_step_desc(i8::MAX - 1..=i8::MAX, 100).until_partial(-100).collect::<Vec<_>>(),
+        [127, 126, 27, 26]
+    );
+}
@@ -0,0 +1,67 @@
+use iter_cyclic::{range_skip, range_skip_desc, range_step, range_step_idx};
+
+#[test]
+fn skip_lookup() {
+    let iter = range_skip(0_u8..5, 200);
+    assert!(iter.contains(0));
+    assert!(iter.contains(211));
+    assert!(!iter.contains(6));
+    assert!(!iter.contains(212));
+    assert_eq!(iter.position_of(206), Some(6));
+    assert_eq!(iter.value_at(11), Some(211));
+    assert_eq!(iter.value_at(12), None);
+}
+
+#[test]
+fn skip_lookup_ignores_consumed() {
+    let mut iter = range_skip(0_u8..5, 200);
+    iter.nth(8);
+    assert_eq!(iter.position_of(1), Some(1));
+    assert_eq!(iter.value_at(0), Some(0));
+}
+
+#[test]
+fn skip_lookup_limits() {
+    let iter = range_skip(0_u8..1, 10).blocks(2);
+    assert!(iter.contains(13));
+    assert!(!iter.contains(24));
+    let iter = range_skip(0_u8..1, 10).until_partial(24);
+    assert_eq!(iter.position_of(24), Some(4));
+    assert_eq!(iter.position_of(25), None);
+}
+
+#[test]
+fn skip_lookup_desc() {
+    let iter = range_skip_desc(-124_i8..=-123, 1);
+    assert_eq!(iter.position_of(-126), Some(2));
+    assert_eq!(iter.value_at(3), Some(-127));
+    assert!(!iter.contains(-128));
+}
+
+#[test]
+fn skip_lookup_wide() {
+    let iter = range_skip(0_u128..1, 1 << 40);
+    let value = 3 * ((1 << 40) + 2) + 1;
+    assert_eq!(iter.position_of(value), Some(7));
+    assert_eq!(iter.value_at(7), Some(value));
+}
+
+#[test]
+fn step_lookup_wrap() {
+    let iter = range_step(0_u8, 0, 100).wrapping();
+    assert_eq!(iter.value_at(3), Some(44));
+    assert_eq!(iter.value_at(1000), Some((1000 * 100 % 256) as u8));
+    assert_eq!(iter.position_of(200), Some(2));
+    assert_eq!(iter.position_of(44), None);
+}
+
+#[test]
+fn idx_lookup() {
+    let iter = range_step_idx(3, 4, 7, 22);
+    assert_eq!(iter.position_of(10), Some(2));
+    assert_eq!(iter.position_of(12), None);
+    assert_eq!(iter.position_of(24), None);
+    assert_eq!(iter.value_at(5), Some(18));
+    assert_eq!(iter.value_at(6), None);
+    assert!(!range_step_idx(0, 7, 7, 22).contains(0));
+}
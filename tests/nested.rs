@@ -0,0 +1,69 @@
+use iter_cyclic::{range_step_idx, range_step_nested, try_range_step_nested, CyclicError, StridePattern};
+
+#[test]
+fn one_level_is_range_step_idx() {
+    assert_eq!(
+        range_step_nested([(0, 2, 7)], 22).collect::<Vec<_>>(),
+        range_step_idx(0, 2, 7, 22).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn two_levels() {
+    let iter = range_step_nested([(0, 49, 100), (0, 2, 7)], 250);
+    assert_eq!(iter.len(), 42);
+    let idx: Vec<usize> = iter.collect();
+    assert_eq!(idx[18..24], [42, 43, 44, 100, 101, 102]);
+    assert_eq!(idx.last(), Some(&144));
+}
+
+#[test]
+fn three_levels_double_ended() {
+    let iter = range_step_nested([(10, 29, 40), (2, 7, 10), (1, 1, 3)], 80);
+    assert_eq!(iter.clone().collect::<Vec<_>>(), [13, 16, 23, 26, 53, 56, 63, 66]);
+    assert_eq!(iter.clone().rev().collect::<Vec<_>>(), [66, 63, 56, 53, 26, 23, 16, 13]);
+
+    let mut iter = iter;
+    assert_eq!(iter.nth(2), Some(23));
+    assert_eq!(iter.nth_back(3), Some(53));
+    assert_eq!(iter.next(), Some(26));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.value_at(7), Some(66));
+}
+
+#[test]
+fn no_levels() {
+    assert_eq!(range_step_nested([], 4).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_eq!(range_step_nested([], 0).count(), 0);
+}
+
+#[test]
+fn invalid_level() {
+    assert_eq!(range_step_nested([(0, 9, 20), (5, 4, 6)], 100).count(), 0);
+    assert_eq!(
+        try_range_step_nested([(0, 9, 20), (5, 4, 6)], 100).unwrap_err(),
+        CyclicError::InvertedBounds { start: 5, end: 4 }
+    );
+    assert_eq!(
+        try_range_step_nested([(0, 9, 20), (0, 6, 6)], 100).unwrap_err(),
+        CyclicError::BlockWiderThanStep { stop: 6, step: 6 }
+    );
+    assert_eq!(
+        try_range_step_nested([(0, 9, 200)], 100).unwrap_err(),
+        CyclicError::StepExceedsLength { step: 200, len: 100 }
+    );
+}
+
+#[test]
+fn compose() {
+    let outer = StridePattern::new(1, 3, 5, 20);
+    assert_eq!(outer.iter().collect::<Vec<_>>(), [1, 2, 3, 6, 7, 8, 11, 12, 13, 16, 17, 18]);
+
+    let inner = StridePattern::new(1, 1, 3, 100);
+    let iter = outer.compose(&inner);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [17, 12, 7, 2]);
+
+    let inner = StridePattern::new(0, 1, 5, 100);
+    assert_eq!(outer.compose(&inner).collect::<Vec<_>>(), [1, 2, 8, 11]);
+}
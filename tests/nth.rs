@@ -0,0 +1,67 @@
+use iter_cyclic::{range_skip, range_step, range_step_idx, RangeStepIter};
+
+#[test]
+fn range_skip_nth() {
+    let mut iter = range_skip(0_u8..5, 200);
+    assert_eq!(iter.nth(7), Some(207));
+    assert_eq!(iter.next(), Some(208));
+    assert_eq!(iter.nth(10), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn range_skip_nth_large() {
+    let mut iter = range_skip(0_u64..2, 7);
+    assert_eq!(iter.nth(3_000_000), Some(10_000_000));
+    assert_eq!(iter.next(), Some(10_000_001));
+    assert_eq!(iter.next(), Some(10_000_002));
+    assert_eq!(iter.next(), Some(10_000_010));
+}
+
+#[test]
+fn range_skip_skip() {
+    assert_eq!(range_skip(0_u8..5, 200).skip(5).collect::<Vec<_>>(), [5, 206, 207, 208, 209, 210, 211]);
+}
+
+#[test]
+fn range_skip_nth_back() {
+    let mut iter = range_skip(0_u8..1, 200);
+    assert_eq!(iter.nth_back(2), Some(1));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn range_step_nth() {
+    assert_eq!(range_step(i8::MIN, -124, 51).nth(20), Some(76));
+    assert_eq!(range_step(i8::MIN, -124, 51).last(), Some(80));
+}
+
+#[test]
+fn range_step_idx_nth() {
+    let mut iter = range_step_idx(0, 2, 7, usize::MAX);
+    assert_eq!(iter.nth(3_000_000), Some(7_000_000));
+    assert_eq!(iter.nth(1), Some(7_000_002));
+    assert_eq!(iter.next(), Some(7_000_007));
+}
+
+#[test]
+fn range_step_idx_nth_past_end() {
+    let mut iter = range_step_idx(0, 2, 7, 22);
+    assert_eq!(iter.nth(9), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn range_step_idx_nth_meet() {
+    let mut iter = range_step_idx(0, 2, 7, 22);
+    assert_eq!(iter.nth_back(2), Some(14));
+    assert_eq!(iter.nth(5), Some(9));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn vec_iter_nth() {
+    let vec: Vec<u8> = (0..21).collect();
+    assert_eq!(vec.range_step_iter(0, 2, 5).nth(7), Some(11));
+}
@@ -0,0 +1,108 @@
+use iter_cyclic::{range_skip, range_skip_bounds, range_skip_desc, range_step, range_step_desc, OverflowPolicy};
+
+#[test]
+fn skip_stop_is_default() {
+    assert_eq!(
+        range_skip(0_u8..5, 200).overflow(OverflowPolicy::Stop).collect::<Vec<_>>(),
+        range_skip(0_u8..5, 200).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn skip_saturate() {
+    let iter = range_skip_bounds(0_u8..=5, 200).overflow(OverflowPolicy::Saturate);
+    assert_eq!(iter.len(), 12);
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 206, 207, 208, 209, 210, 211]);
+
+    let iter = range_skip_bounds(0_u8..=5, 120).overflow(OverflowPolicy::Saturate);
+    assert_eq!(iter.len(), 16);
+    assert_eq!(iter.rev().take(5).collect::<Vec<_>>(), [255, 254, 253, 252, 131]);
+}
+
+#[test]
+fn skip_wrap() {
+    let iter = range_skip_bounds(i8::MAX - 1..=i8::MAX, 1).wrapping();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    assert_eq!(iter.take(6).collect::<Vec<_>>(), [126, 127, -127, -126, -124, -123]);
+}
+
+#[test]
+fn skip_zero_never_cycles() {
+    assert_eq!(range_skip_bounds(250_u8.., 0).wrapping().count(), 6);
+    assert_eq!(range_skip_bounds(250_u8.., 0).overflow(OverflowPolicy::Panic).count(), 6);
+}
+
+#[test]
+fn skip_desc_saturate() {
+    assert_eq!(
+        range_skip_desc(2_u8..=4, 1).overflow(OverflowPolicy::Saturate).collect::<Vec<_>>(),
+        [4, 3, 2, 0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "the next block overflows the output type")]
+fn skip_panic() {
+    let mut iter = range_skip_bounds(250_u8..=252, 2).overflow(OverflowPolicy::Panic);
+    assert_eq!(iter.nth(2), Some(252));
+    iter.next();
+}
+
+#[test]
+#[should_panic(expected = "the next block overflows the output type")]
+fn skip_panic_from_back() {
+    range_skip_bounds(250_u8..=252, 2).overflow(OverflowPolicy::Panic).next_back();
+}
+
+#[test]
+fn step_saturate() {
+    assert_eq!(
+        range_step(0_u8, 5, 20).overflow(OverflowPolicy::Saturate).skip(72).collect::<Vec<_>>(),
+        [240, 241, 242, 243, 244, 245]
+    );
+    assert_eq!(
+        range_step_desc(254_u8..=255, 100).overflow(OverflowPolicy::Saturate).collect::<Vec<_>>(),
+        [255, 254, 155, 154, 55, 54]
+    );
+}
+
+#[test]
+fn step_wrap() {
+    let iter = range_step(0_u8, 0, 100).wrapping();
+    assert_eq!(iter.take(6).collect::<Vec<_>>(), [0, 100, 200, 44, 144, 244]);
+}
+
+#[test]
+#[should_panic(expected = "the next block overflows the output type")]
+fn step_panic() {
+    range_step(0_u8, 5, 20).overflow(OverflowPolicy::Panic).for_each(drop);
+}
+
+#[test]
+#[should_panic(expected = "the next block overflows the output type")]
+fn step_panic_rev() {
+    range_step(0_u8, 1, 100).overflow(OverflowPolicy::Panic).rev().for_each(drop);
+}
+
+#[test]
+fn step_panic_rev_within_limits() {
+    let iter = range_step(0_u8, 1, 100).overflow(OverflowPolicy::Panic).blocks(2);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [101, 100, 1, 0]);
+}
+
+#[test]
+fn wrap_never_ends() {
+    let iter = range_skip(0_u8..1, 100).wrapping();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    assert_eq!(iter.clone().nth(1000), Some(56));
+    assert_eq!(iter.value_at(1000), Some(56));
+    assert_eq!(iter.blocks(3).last(), Some(205));
+}
+
+#[test]
+fn wrap_keeps_limits() {
+    let iter = range_step(0_u8, 1, 100).blocks(2).wrapping();
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 100, 101]);
+    let iter = range_step(0_u8, 1, 100).overflow(OverflowPolicy::Panic).wrapping();
+    assert_eq!(iter.skip(6).take(2).collect::<Vec<_>>(), [44, 45]);
+}
@@ -0,0 +1,101 @@
+use iter_cyclic::{
+    range_skip_bounds, range_step, range_step_idx, ParsePatternError, ParsePatternErrorKind, SkipPattern,
+    StepPattern, StridePattern,
+};
+
+fn err(position: usize, kind: ParsePatternErrorKind) -> ParsePatternError {
+    ParsePatternError { position, kind }
+}
+
+#[test]
+fn stride_forms() {
+    let pattern = StridePattern::new(0, 2, 7, 22);
+    for src in ["0..=2/7 len 22", "0..3/7 len 22", "0:3:7 len 22", "0..=2 skip 4 len 22", " 0 ..= 2 / 7  len  22 "] {
+        assert_eq!(src.parse(), Ok(pattern), "{src}");
+    }
+    assert_eq!(
+        "0:3:7 len 22".parse::<StridePattern>().unwrap().into_iter().collect::<Vec<_>>(),
+        range_step_idx(0, 2, 7, 22).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn stride_round_trip() {
+    for pattern in [
+        StridePattern::new(0, 2, 7, 22),
+        StridePattern::new(3, 3, 4, usize::MAX),
+        StridePattern::new(usize::MAX, usize::MAX, 1, 0),
+    ] {
+        assert_eq!(pattern.to_string().parse(), Ok(pattern));
+    }
+}
+
+#[test]
+fn inverted_round_trip() {
+    let stride = StridePattern::new(5, 2, 7, 22);
+    assert_eq!(stride.to_string().parse(), Ok(stride));
+    assert_eq!(stride.into_iter().next(), None);
+    let skip = SkipPattern::new(5_u8, 2, 3);
+    assert_eq!(skip.to_string().parse(), Ok(skip));
+    assert_eq!(skip.into_iter().next(), None);
+    let step = StepPattern::new(-1_i8, -4, 20);
+    assert_eq!(step.to_string().parse(), Ok(step));
+    assert_eq!(step.into_iter().next(), None);
+}
+
+#[test]
+fn skip_forms() {
+    let pattern: SkipPattern<u8> = "0..6 skip 200".parse().unwrap();
+    assert_eq!(pattern.to_string(), "0..=5 skip 200");
+    assert_eq!(
+        pattern.into_iter().collect::<Vec<_>>(),
+        range_skip_bounds(0_u8..6, 200).collect::<Vec<_>>()
+    );
+    assert_eq!("-3:0:10".parse(), Ok(SkipPattern::new(-3_i8, -1, 7)));
+    assert_eq!("0..=2/3".parse(), Ok(SkipPattern::new(0_u8, 2, 0)));
+}
+
+#[test]
+fn step_forms() {
+    let pattern: StepPattern<i16> = "-32768..=-32766 skip 97".parse().unwrap();
+    assert_eq!(pattern, StepPattern::new(i16::MIN, i16::MIN + 2, 100));
+    assert_eq!(pattern.to_string().parse(), Ok(pattern));
+    assert_eq!(
+        "0..=5/20".parse::<StepPattern<u8>>().unwrap().into_iter().collect::<Vec<_>>(),
+        range_step(0_u8, 5, 20).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!("".parse::<StridePattern>(), Err(err(0, ParsePatternErrorKind::ExpectedNumber)));
+    assert_eq!("0 2/7".parse::<StridePattern>(), Err(err(2, ParsePatternErrorKind::ExpectedBounds)));
+    assert_eq!("0:3 7".parse::<StridePattern>(), Err(err(4, ParsePatternErrorKind::ExpectedBounds)));
+    assert_eq!("0..=2 7".parse::<StridePattern>(), Err(err(6, ParsePatternErrorKind::ExpectedStep)));
+    assert_eq!("0..=2 skipped".parse::<StridePattern>(), Err(err(6, ParsePatternErrorKind::ExpectedStep)));
+    assert_eq!("5..2/7".parse::<StridePattern>(), Err(err(3, ParsePatternErrorKind::InvertedBounds)));
+    assert_eq!("5..=2 skip 3".parse::<StridePattern>(), Err(err(4, ParsePatternErrorKind::InvertedBounds)));
+    assert_eq!("5..=2/7".parse::<SkipPattern<u8>>(), Err(err(4, ParsePatternErrorKind::InvertedBounds)));
+    assert_eq!("2..2/7".parse::<StridePattern>(), Err(err(3, ParsePatternErrorKind::EmptyBlock)));
+    assert_eq!("0..=2/7 x".parse::<StridePattern>(), Err(err(8, ParsePatternErrorKind::TrailingInput)));
+    assert_eq!("0..=300/7".parse::<StepPattern<u8>>(), Err(err(4, ParsePatternErrorKind::InvalidNumber)));
+    assert_eq!("0..=2/2".parse::<SkipPattern<u8>>(), Err(err(6, ParsePatternErrorKind::StepTooSmall)));
+    assert_eq!("0..=2/7 len 9".parse::<StepPattern<u8>>(), Err(err(12, ParsePatternErrorKind::UnexpectedLen)));
+    assert_eq!(
+        format!("0..=2 skip {}", usize::MAX).parse::<StridePattern>(),
+        Err(err(11, ParsePatternErrorKind::Overflow))
+    );
+}
+
+#[test]
+fn trailing_keywords() {
+    assert_eq!("0..=2/7 len".parse::<StridePattern>(), Err(err(11, ParsePatternErrorKind::ExpectedNumber)));
+    assert_eq!("0..5 skip".parse::<SkipPattern<u8>>(), Err(err(9, ParsePatternErrorKind::ExpectedNumber)));
+    assert_eq!("0..5 skip  ".parse::<StepPattern<u8>>(), Err(err(11, ParsePatternErrorKind::ExpectedNumber)));
+}
+
+#[test]
+fn error_display() {
+    let err = "0..=2/7 x".parse::<StridePattern>().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected input at position 8");
+}
@@ -0,0 +1,79 @@
+use iter_cyclic::{range_skip_bounds, range_step, range_step_idx, SkipPattern, StepPattern, StridePattern};
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn stride_iter_twice() {
+    let pattern = StridePattern::new(3, 4, 7, 22);
+    assert_eq!(pattern.iter().collect::<Vec<_>>(), range_step_idx(3, 4, 7, 22).collect::<Vec<_>>());
+    assert_eq!(pattern.into_iter().len(), 6);
+    assert_eq!(pattern.into_iter().len(), 6);
+}
+
+#[test]
+fn stride_for_loop() {
+    let mut sum = 0;
+    for idx in StridePattern::new(0, 0, 5, 20) {
+        sum += idx;
+    }
+    assert_eq!(sum, 30);
+}
+
+#[test]
+fn stride_invalid_is_empty() {
+    assert_eq!(StridePattern::new(0, 7, 7, 22).into_iter().next(), None);
+}
+
+#[test]
+fn stride_map_key() {
+    let mut map = HashMap::new();
+    map.insert(StridePattern::new(0, 1, 4, 12), 1);
+    map.insert(StridePattern::new(2, 3, 4, 12), 2);
+    assert_eq!(map.get(&StridePattern::new(2, 3, 4, 12)), Some(&2));
+    assert_eq!(map.get(&StridePattern::new(2, 3, 4, 16)), None);
+}
+
+#[test]
+fn stride_display() {
+    assert_eq!(StridePattern::new(0, 2, 7, 22).to_string(), "0..=2/7 len 22");
+}
+
+#[test]
+fn skip_pattern() {
+    let pattern = SkipPattern::new(250_u8, 251, 1);
+    assert_eq!(
+        pattern.into_iter().collect::<Vec<_>>(),
+        range_skip_bounds(250_u8..=251, 1).collect::<Vec<_>>()
+    );
+    assert_eq!(pattern.to_string(), "250..=251 skip 1");
+    assert_eq!(SkipPattern::new(-3_i8, -1, 0).to_string(), "-3..=-1 skip 0");
+}
+
+#[test]
+fn skip_pattern_inverted_is_empty() {
+    assert_eq!(SkipPattern::new(5_u8, 1, 1).into_iter().next(), None);
+}
+
+#[test]
+fn step_pattern() {
+    let pattern = StepPattern::new(0_u8, 5, 20);
+    assert_eq!(pattern.iter().collect::<Vec<_>>(), range_step(0_u8, 5, 20).collect::<Vec<_>>());
+    assert_eq!(pattern.to_string(), "0..=5/20");
+    assert_eq!(StepPattern::new(5_u8, 1, 20).into_iter().next(), None);
+}
+
+#[test]
+fn step_pattern_invalid_is_empty() {
+    assert_eq!(StepPattern::new(0_u8, 5, 0).into_iter().next(), None);
+    assert_eq!(StepPattern::new(0_u8, 5, 5).into_iter().next(), None);
+    assert_eq!(StepPattern::new(0_u8, 0, 300).into_iter().next(), None);
+    let pattern: StepPattern<u8> = "0..=5/0".parse().unwrap();
+    assert_eq!(pattern.iter().count(), 0);
+    let pattern: StepPattern<u8> = "0..=0/300".parse().unwrap();
+    assert_eq!(pattern.iter().count(), 0);
+}
+
+#[test]
+fn patterns_hash() {
+    let set: HashSet<_> = [StepPattern::new(0_u8, 5, 20), StepPattern::new(0, 5, 20), StepPattern::new(0, 5, 21)].into();
+    assert_eq!(set.len(), 2);
+}
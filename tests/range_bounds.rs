@@ -0,0 +1,116 @@
+use std::ops::Bound;
+
+use iter_cyclic::{
+    range_skip, range_skip_bounds, range_step, range_step_bounds, range_step_idx, range_step_idx_bounds,
+    try_range_skip_bounds, try_range_step_bounds, try_range_step_idx_bounds, CyclicError,
+};
+
+#[test]
+fn skip_exclusive_eq_inclusive() {
+    assert_eq!(
+        range_skip_bounds(0_u8..6, 200).collect::<Vec<_>>(),
+        range_skip_bounds(0_u8..=5, 200).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        range_skip_bounds(0_u8..=5, 200).collect::<Vec<_>>(),
+        range_skip(0_u8..5, 200).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn skip_from() {
+    assert_eq!(range_skip_bounds(i8::MAX - 2.., 0).collect::<Vec<_>>(), [125, 126, 127]);
+}
+
+#[test]
+fn skip_to_inclusive() {
+    assert_eq!(
+        range_skip_bounds(..=-127_i8, 2).take(6).collect::<Vec<_>>(),
+        [-128, -127, -124, -123, -120, -119]
+    );
+}
+
+#[test]
+fn skip_full() {
+    assert_eq!(range_skip_bounds::<u8, _>(.., 0).count(), 256);
+}
+
+#[test]
+fn skip_empty() {
+    assert_eq!(range_skip_bounds(5_u8..5, 10).next(), None);
+    assert_eq!(range_skip_bounds(..0_u8, 10).next(), None);
+    assert_eq!(
+        try_range_skip_bounds((Bound::Excluded(u8::MAX), Bound::Unbounded), 10).unwrap().next(),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "start 10 > end 0")]
+#[allow(clippy::reversed_empty_ranges)]
+fn skip_inverted() {
+    range_skip_bounds(10..0, 0);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn try_skip_inverted() {
+    assert_eq!(
+        try_range_skip_bounds(10..=0, 0).unwrap_err(),
+        CyclicError::InvertedBounds { start: 10, end: 0 }
+    );
+}
+
+#[test]
+fn try_skip_full_range_overflow() {
+    assert_eq!(
+        try_range_skip_bounds(0_u128.., 5).unwrap_err(),
+        CyclicError::Overflow { start: 0, end: u128::MAX, step: 5 }
+    );
+    assert_eq!(
+        try_range_skip_bounds(i128::MIN.., 1).unwrap_err(),
+        CyclicError::Overflow { start: i128::MIN, end: i128::MAX, step: 1 }
+    );
+    assert_eq!(try_range_skip_bounds(0_u128.., 0).unwrap().next(), Some(0));
+}
+
+#[test]
+fn step_bounds() {
+    assert_eq!(
+        range_step_bounds(i8::MIN..-123, 51).collect::<Vec<_>>(),
+        range_step(i8::MIN, -124, 51).collect::<Vec<_>>()
+    );
+    assert_eq!(range_step_bounds(1_u8..1, 51).next(), None);
+}
+
+#[test]
+fn step_bounds_invalid_is_empty() {
+    assert_eq!(range_step_bounds(0_u8..=5, 0).next(), None);
+    assert_eq!(range_step_bounds(0_u8..=5, 300).next(), None);
+    assert_eq!(range_step_bounds(0_u8..=5, 5).next(), None);
+}
+
+#[test]
+fn try_step_bounds() {
+    assert_eq!(
+        try_range_step_bounds(0_u8.., 20).unwrap_err(),
+        CyclicError::BlockWiderThanStep { stop: u8::MAX, step: 20 }
+    );
+}
+
+#[test]
+fn idx_bounds() {
+    assert_eq!(
+        range_step_idx_bounds(0..3, 7, 22).collect::<Vec<_>>(),
+        range_step_idx(0, 2, 7, 22).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        range_step_idx_bounds((Bound::Excluded(0), Bound::Included(2)), 7, 22).collect::<Vec<_>>(),
+        [1, 2, 8, 9, 15, 16]
+    );
+}
+
+#[test]
+fn try_idx_bounds_empty() {
+    assert_eq!(try_range_step_idx_bounds(3..3, 7, 22).unwrap().next(), None);
+}
@@ -0,0 +1,54 @@
+use iter_cyclic::{range_skip, range_step, range_step_idx, RangeStepIter, RangeStepVec};
+
+#[test]
+fn range_skip_len() {
+    let mut iter = range_skip(0_u8..5, 200);
+    assert_eq!(iter.len(), 12);
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 10);
+}
+
+#[test]
+fn range_skip_size_hint_wide() {
+    assert_eq!(range_skip(0_u64..0, 1).size_hint(), (1 << 63, Some(1 << 63)));
+    assert_eq!(range_skip(0..u128::MAX, 0).size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn range_step_len() {
+    assert_eq!(range_step(i8::MIN, -124, 51).len(), 25);
+    assert_eq!(range_step(0_u8, 6, 5).len(), 0);
+}
+
+#[test]
+fn range_step_idx_len() {
+    let mut iter = range_step_idx(0, 2, 7, 22);
+    assert_eq!(iter.len(), 9);
+    assert_eq!(iter.by_ref().count(), 9);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn fused() {
+    let mut iter = range_step_idx(0, 0, 1, 1);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn vec_iter_len() {
+    let vec: Vec<u8> = (0..12).collect();
+    assert_eq!(vec.clone().range_step_iter(0, 1, 4).len(), 6);
+    assert_eq!(RangeStepVec::range_step_iter(&vec, 0, 1, 4).len(), 6);
+}
+
+#[test]
+fn range_step_vec_capacity() {
+    let vec: Vec<u8> = (0..=21).collect();
+    let new_vec = vec.range_step_vec(0, 2, 7);
+    assert_eq!(new_vec.capacity(), 9);
+}
@@ -0,0 +1,49 @@
+use iter_cyclic::RangeStepBlocks;
+
+#[test]
+fn chunks_are_whole_blocks() {
+    let vec: Vec<u8> = (0..22).collect();
+    let chunks: Vec<&[u8]> = vec.range_step_chunks(1, 2, 7).collect();
+    assert_eq!(chunks, [&[1, 2][..], &[8, 9], &[15, 16]]);
+}
+
+#[test]
+fn chunks_from_both_ends() {
+    let arr: [u8; 12] = std::array::from_fn(|i| i as u8);
+    let mut chunks = arr.range_step_chunks(0, 1, 3);
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks.next_back(), Some(&[9, 10][..]));
+    assert_eq!(chunks.nth(1), Some(&[3, 4][..]));
+    assert_eq!(chunks.clone().count(), 1);
+    assert_eq!(chunks.nth_back(1), None);
+    assert_eq!(chunks.next(), None);
+}
+
+#[test]
+fn chunks_mut_are_disjoint() {
+    let mut vec: Vec<String> = (0..8).map(|i| i.to_string()).collect();
+    let mut chunks = vec.range_step_chunks_mut(0, 1, 4);
+    let last = chunks.next_back().unwrap();
+    let first = chunks.next().unwrap();
+    first.swap_with_slice(last);
+    assert!(chunks.next().is_none());
+    assert_eq!(vec, ["4", "5", "2", "3", "0", "1", "6", "7"]);
+}
+
+#[test]
+fn chunks_mut_skip() {
+    let mut boxed: Box<[u8]> = vec![0; 10].into_boxed_slice();
+    let mut chunks = boxed.range_step_chunks_mut(0, 0, 2);
+    chunks.nth(1).unwrap()[0] = 1;
+    chunks.nth_back(1).unwrap()[0] = 2;
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(*boxed, [0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+}
+
+#[test]
+fn chunks_invalid_are_empty() {
+    let mut arr = [0_u8; 8];
+    assert_eq!(arr.range_step_chunks(0, 4, 4).count(), 0);
+    assert_eq!(arr.range_step_chunks_mut(3, 1, 4).count(), 0);
+    assert_eq!(arr.range_step_chunks(0, 1, 9).count(), 0);
+}
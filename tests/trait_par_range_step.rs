@@ -0,0 +1,54 @@
+use iter_cyclic::{range_step_idx, RangeStepBlocks, RangeStepPar, RangeStepVec};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn for_each_matches_sequential() {
+    let mut par: Vec<u64> = (0..10_000).collect();
+    let mut seq = par.clone();
+    par.par_range_step_for_each(3, 9, 16, 8, |idx, elem| *elem += idx as u64 * 2);
+    seq.range_step_map_indexed(3, 9, 16, |idx, elem| *elem += idx as u64 * 2);
+    assert_eq!(par, seq);
+}
+
+#[test]
+fn for_each_visits_each_index_once() {
+    let mut arr = [0_u8; 100];
+    let calls = AtomicUsize::new(0);
+    arr.par_range_step_for_each(0, 2, 7, 5, |_, elem| {
+        *elem += 1;
+        calls.fetch_add(1, Ordering::Relaxed);
+    });
+    assert_eq!(calls.into_inner(), range_step_idx(0, 2, 7, 100).len());
+    assert!(range_step_idx(0, 2, 7, 100).all(|idx| arr[idx] == 1));
+}
+
+#[test]
+fn map_thread_counts() {
+    let base: Vec<i32> = (0..50).collect();
+    let mut seq = base.clone();
+    seq.range_step_map(1, 2, 5, |val| -val);
+    for threads in [0, 1, 3, 10, 64] {
+        let mut par = base.clone();
+        par.par_range_step_map(1, 2, 5, threads, |val| -val);
+        assert_eq!(par, seq);
+    }
+}
+
+#[test]
+fn chunks_mut_matches_sequential() {
+    let mut par: Vec<String> = (0..30).map(|i| i.to_string()).collect();
+    let mut seq = par.clone();
+    par.par_range_step_chunks_mut(0, 3, 6, 4, |block| block.rotate_left(1));
+    for block in seq.range_step_chunks_mut(0, 3, 6) {
+        block.rotate_left(1);
+    }
+    assert_eq!(par, seq);
+}
+
+#[test]
+fn invalid_is_untouched() {
+    let mut arr = [1_u8; 8];
+    arr.par_range_step_map(0, 4, 4, 2, |_| 0);
+    arr.par_range_step_chunks_mut(0, 1, 9, 2, |block| block.fill(0));
+    assert_eq!(arr, [1; 8]);
+}
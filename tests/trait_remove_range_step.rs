@@ -0,0 +1,53 @@
+use iter_cyclic::{range_step_idx, RangeStepRemove};
+
+#[test]
+fn drain_keeps_order() {
+    let mut vec: Vec<u8> = (0..22).collect();
+    let drained: Vec<u8> = vec.range_step_drain(0, 2, 7).collect();
+    assert_eq!(drained, range_step_idx(0, 2, 7, 22).map(|idx| idx as u8).collect::<Vec<_>>());
+    assert_eq!(vec, [3, 4, 5, 6, 10, 11, 12, 13, 17, 18, 19, 20, 21]);
+}
+
+#[test]
+fn drain_without_consuming() {
+    let mut vec: Vec<String> = (0..6).map(|i| i.to_string()).collect();
+    drop(vec.range_step_drain(1, 1, 2));
+    assert_eq!(vec, ["0", "2", "4"]);
+}
+
+#[test]
+fn drain_from_back() {
+    let mut vec: Vec<u8> = (0..12).collect();
+    let mut drain = vec.range_step_drain(0, 1, 4);
+    assert_eq!(drain.len(), 6);
+    assert_eq!(drain.next_back(), Some(9));
+    assert_eq!(drain.nth(1), Some(1));
+}
+
+#[test]
+fn drain_in_place() {
+    let mut vec: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+    vec.reserve(100);
+    let capacity = vec.capacity();
+    assert_eq!(vec.range_step_drain(1, 2, 4).collect::<Vec<_>>(), ["1", "2", "5", "6"]);
+    assert_eq!(vec, ["0", "3", "4", "7", "8", "9"]);
+    assert_eq!(vec.capacity(), capacity);
+}
+
+#[test]
+fn drain_invalid_is_empty() {
+    let mut vec: Vec<u8> = (0..12).collect();
+    assert_eq!(vec.range_step_drain(0, 4, 4).count(), 0);
+    assert_eq!(vec.range_step_drain(0, 1, 13).count(), 0);
+    assert_eq!(vec.len(), 12);
+}
+
+#[test]
+fn retain() {
+    let mut vec: Vec<Vec<u8>> = (0..10).map(|i| vec![i]).collect();
+    vec.range_step_retain(2, 3, 4);
+    assert_eq!(vec, [vec![2], vec![3], vec![6], vec![7]]);
+
+    vec.range_step_retain(1, 0, 2);
+    assert!(vec.is_empty());
+}
@@ -0,0 +1,53 @@
+use iter_cyclic::RangeStepSlice;
+use std::collections::VecDeque;
+
+#[test]
+fn ref_non_copy() {
+    let words: Vec<String> = "the quick brown fox jumps over".split(' ').map(String::from).collect();
+    let mut iter = words.range_step_ref(0, 0, 2);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.clone().map(String::as_str).collect::<Vec<_>>(), ["the", "brown", "jumps"]);
+    assert_eq!(iter.next_back().map(String::as_str), Some("jumps"));
+}
+
+#[test]
+fn mut_both_ends() {
+    let mut rows: Vec<Vec<u8>> = vec![Vec::new(); 8];
+    let mut iter = rows.range_step_mut(1, 2, 4);
+    iter.next().unwrap().push(1);
+    iter.next_back().unwrap().push(6);
+    iter.nth(1).unwrap().push(5);
+    assert!(iter.next().is_none());
+    assert_eq!(rows, [vec![], vec![1], vec![], vec![], vec![], vec![5], vec![6], vec![]]);
+}
+
+#[test]
+fn invalid_is_empty() {
+    let mut arr = [0_u8; 10];
+    assert_eq!(arr.range_step_ref(3, 2, 4).count(), 0);
+    assert_eq!(arr.range_step_mut(0, 4, 4).count(), 0);
+    assert_eq!(arr.range_step_mut(0, 1, 11).count(), 0);
+}
+
+#[test]
+fn fill_and_to_vec() {
+    let mut boxed: Box<[String]> = vec![String::new(); 6].into_boxed_slice();
+    boxed.range_step_fill(1, 1, 3, "x".to_string());
+    assert_eq!(boxed.range_step_to_vec(0, 1, 3), ["", "x", "", "x"]);
+}
+
+#[test]
+fn deque_two_halves() {
+    let mut deque: VecDeque<String> = VecDeque::with_capacity(6);
+    deque.extend(["c", "d", "e", "f"].map(String::from));
+    deque.push_front("b".to_string());
+    deque.push_front("a".to_string());
+    let (front, back) = deque.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+
+    for elem in deque.range_step_mut(0, 0, 3) {
+        elem.make_ascii_uppercase();
+    }
+    assert_eq!(deque.range_step_to_vec(0, 1, 3), ["A", "b", "D", "e"]);
+    assert_eq!(deque.range_step_ref(2, 2, 3).rev().collect::<Vec<_>>(), ["f", "c"]);
+}
@@ -41,3 +41,17 @@ fn range_step_neg() {
     let vec: Vec<i8> = (-10..24).collect();
     assert_eq!(vec.range_step_iter(0, 2, 7).collect::<Vec<_>>(), vec![-10, -9, -8, -3, -2, -1, 4, 5, 6, 11, 12, 13]);
 }
+
+#[test]
+fn array_by_value() {
+    let arr: [u16; 10] = std::array::from_fn(|i| i as u16 * 10);
+    let iter = arr.range_step_iter(0, 1, 3);
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [70, 60, 40, 30, 10, 0]);
+}
+
+#[test]
+fn boxed_slice_by_value() {
+    let boxed: Box<[char]> = "abcdefgh".chars().collect();
+    assert_eq!(boxed.range_step_iter(1, 1, 2).collect::<String>(), "bdfh");
+}
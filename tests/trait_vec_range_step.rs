@@ -1,48 +1,57 @@
-use iter_cyclic::RangeStepVec;
+use iter_cyclic::{Exhausted, RangeStepSliceIter, RangeStepVec};
+use std::collections::VecDeque;
 
 #[test]
+#[allow(deprecated)]
 fn empty_vector() {
     let vec: Vec<char> = Vec::new();
     assert_eq!(vec.range_step_iter(0, 3, 5).collect::<Vec<_>>(), Vec::<char>::new());
 }
 
 #[test]
+#[allow(deprecated)]
 fn step_by_five() {
     let vec = (0..=254).collect::<Vec<u8>>();
     assert_eq!(vec.range_step_iter(0, 0, 5).collect::<Vec<_>>(), (0..=254).step_by(5).collect::<Vec<u8>>());
 }
 
 #[test]
+#[allow(deprecated)]
 fn start_gt_stop() {
     let vec = vec![0u8; 100];
     assert_eq!(vec.range_step_iter(1, 0, 5).collect::<Vec<_>>(), Vec::<u8>::new());
 }
 
 #[test]
+#[allow(deprecated)]
 fn stop_gt_step() {
     let vec = vec![0u8; 100];
     assert_eq!(vec.range_step_iter(0, 6, 5).collect::<Vec<_>>(), Vec::<u8>::new());
 }
 
 #[test]
+#[allow(deprecated)]
 fn step_gt_vec_len() {
     let vec = vec![0u8; 255];
     assert_eq!(vec.range_step_iter(0, 6, 256).collect::<Vec<_>>(), Vec::<u8>::new());
 }
 
 #[test]
+#[allow(deprecated)]
 fn one_full_step() {
     let vec = (0..=254).collect::<Vec<u8>>();
     assert_eq!(vec.clone().range_step_iter(0, 254, 255).collect::<Vec<_>>(), vec);
 }
 
 #[test]
+#[allow(deprecated)]
 fn range_two_step_four() {
     let vec = (0..12).collect::<Vec<u8>>();
     assert_eq!(vec.range_step_iter(0, 1, 4).collect::<Vec<_>>(), vec![0, 1, 4, 5, 8, 9u8]);
 }
 
 #[test]
+#[allow(deprecated)]
 fn range_step_neg() {
     let vec: Vec<i8> = (-10..24).collect();
     assert_eq!(vec.range_step_iter(0, 2, 7).collect::<Vec<_>>(), vec![-10, -9, -8, -3, -2, -1, 4, 5, 6, 11, 12, 13]);
@@ -91,3 +100,146 @@ fn range_step_tuple_vec() {
     let new_vec = vec.range_step_vec(0, 1, 4);
     assert_eq!(new_vec, [("Vessel", "big"), ("yacht", "expensive"), ("sailboat", "beautiful"), ("steamboat", "old")]);
 }
+
+#[test]
+#[allow(deprecated)]
+fn slice_and_array() {
+    let mut arr: [u8; 12] = [0; 12];
+    arr.range_step_value(0, 1, 4, 1);
+    arr[6..].range_step_values(0, 0, 3, [7, 8].into_iter());
+    assert_eq!(arr, [1, 1, 0, 0, 1, 1, 7, 0, 1, 8, 0, 0]);
+    assert_eq!(arr[1..].range_step_vec(0, 0, 4), [1, 1]);
+
+    let boxed: Box<[u8]> = arr.into();
+    assert_eq!(boxed.range_step_iter(2, 3, 4).collect::<Vec<_>>(), [0, 0, 7, 0, 0, 0]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deque_two_halves() {
+    let mut deque: VecDeque<u8> = VecDeque::with_capacity(8);
+    deque.extend(4..10);
+    deque.push_front(3);
+    deque.push_front(2);
+    let (front, back) = deque.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+
+    assert_eq!(deque.range_step_vec(1, 2, 3), [3, 4, 6, 7]);
+    assert_eq!(deque.range_step_iter(1, 2, 3).rev().collect::<Vec<_>>(), [7, 6, 4, 3]);
+    deque.range_step_value(0, 0, 2, 0);
+    deque.range_step_values(1, 1, 4, [10, 20, 30].into_iter());
+    assert_eq!(deque, [0, 10, 0, 5, 0, 20, 0, 9]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn slice_iter_is_named() {
+    struct Rows<'a> {
+        iter: RangeStepSliceIter<'a, u8>,
+    }
+    let vec: Vec<u8> = (0..12).collect();
+    let rows = Rows { iter: vec.as_slice().range_step_iter(0, 1, 4) };
+    let mut iter = rows.iter.clone();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.nth_back(1), Some(8));
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 4, 5]);
+    assert_eq!(rows.iter.last(), Some(9));
+}
+
+#[test]
+fn both_traits_in_scope() {
+    use iter_cyclic::RangeStepIter;
+
+    let vec: Vec<u8> = (0..12).collect();
+    let borrowed = vec.range_step_copied(0, 0, 3);
+    assert_eq!(borrowed.collect::<Vec<_>>(), [0, 3, 6, 9]);
+    let owned = vec.range_step_iter(0, 0, 3);
+    assert_eq!(owned.collect::<Vec<_>>(), [0, 3, 6, 9]);
+}
+
+#[test]
+fn copied_vec_and_slices() {
+    let vec: Vec<i8> = (-10..24).collect();
+    assert_eq!(vec.range_step_copied(0, 2, 7).collect::<Vec<_>>(), [-10, -9, -8, -3, -2, -1, 4, 5, 6, 11, 12, 13]);
+    assert_eq!(vec[1..].range_step_copied(0, 0, 5).len(), 6);
+    let boxed: Box<[i8]> = vec.into();
+    assert_eq!(boxed.range_step_copied(3, 4, 8).rev().collect::<Vec<_>>(), [18, 17, 10, 9, 2, 1, -6, -7]);
+}
+
+#[test]
+fn copied_deque_two_halves() {
+    let mut deque: VecDeque<u8> = VecDeque::with_capacity(8);
+    deque.extend(4..10);
+    deque.push_front(3);
+    deque.push_front(2);
+    let mut iter = deque.range_step_copied(1, 2, 3);
+    assert_eq!(iter.nth_back(1), Some(6));
+    assert_eq!(iter.collect::<Vec<_>>(), [3, 4]);
+}
+
+#[test]
+fn write_streams_into_buffers() {
+    let mut data = 0..10_u8;
+    let mut buffers = vec![vec![0_u8; 8]; 3];
+    let mut reports = Vec::new();
+    for buffer in buffers.iter_mut() {
+        let report = buffer.range_step_write(0, 1, 4, data);
+        reports.push((report.written, report.exhausted));
+        data = report.rest;
+    }
+    assert_eq!(reports, [(4, Exhausted::Pattern), (4, Exhausted::Pattern), (2, Exhausted::Source)]);
+    assert_eq!(buffers[2], [8, 9, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn write_exact_keeps_source_unpolled() {
+    let mut vec = vec![0_u8; 6];
+    let mut source = [1, 2, 3].into_iter();
+    let report = vec.range_step_write(0, 0, 2, source.by_ref());
+    assert_eq!((report.written, report.exhausted), (3, Exhausted::Pattern));
+    assert_eq!(vec, [1, 0, 2, 0, 3, 0]);
+    assert_eq!(source.len(), 0);
+}
+
+#[test]
+fn write_deque_and_invalid() {
+    let mut deque: VecDeque<u8> = (3..8).collect();
+    deque.push_front(2);
+    let report = deque.range_step_write(1, 1, 2, [10, 20].into_iter());
+    assert_eq!((report.written, report.exhausted), (2, Exhausted::Source));
+    assert_eq!(deque, [2, 10, 4, 20, 6, 7]);
+
+    let report = deque.range_step_write(0, 2, 2, [1].into_iter());
+    assert_eq!((report.written, report.exhausted), (0, Exhausted::Pattern));
+    assert_eq!(report.rest.len(), 1);
+}
+
+#[test]
+fn map_one_channel() {
+    let mut frames: Vec<f32> = vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0];
+    frames.range_step_map(1, 1, 3, |sample| sample * 0.5);
+    assert_eq!(frames, [1.0, 0.5, 1.0, 2.0, 1.0, 2.0]);
+}
+
+#[test]
+fn map_indexed_deque_two_halves() {
+    let mut deque: VecDeque<usize> = VecDeque::with_capacity(8);
+    deque.extend([0; 6]);
+    deque.drain(..5);
+    deque.extend([0; 7]);
+    let mut visited = Vec::new();
+    deque.range_step_map_indexed(1, 3, 4, |idx, elem| {
+        *elem = idx;
+        visited.push(idx);
+    });
+    assert_eq!(visited, [1, 2, 3, 5, 6, 7]);
+    assert_eq!(deque, [0, 1, 2, 3, 0, 5, 6, 7]);
+}
+
+#[test]
+fn map_invalid_is_untouched() {
+    let mut vec = vec![1_u8; 4];
+    vec.range_step_map(0, 4, 4, |_| 0);
+    vec.range_step_map_indexed(3, 1, 2, |_, elem| *elem = 0);
+    assert_eq!(vec, [1; 4]);
+}
@@ -0,0 +1,76 @@
+use iter_cyclic::{range_skip, range_step, range_step_idx, try_range_skip, try_range_step, try_range_step_idx, CyclicError};
+
+#[test]
+fn skip_ok_eq_range_skip() {
+    assert_eq!(
+        try_range_skip(0_u8..1, 200).unwrap().collect::<Vec<_>>(),
+        range_skip(0_u8..1, 200).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn skip_inverted_bounds() {
+    assert_eq!(try_range_skip(10..0, 0).unwrap_err(), CyclicError::InvertedBounds { start: 10, end: 0 });
+}
+
+#[test]
+fn skip_conversion() {
+    assert_eq!(try_range_skip(1_u8..10, 256).unwrap_err(), CyclicError::SkipConversion { skip: 256 });
+}
+
+#[test]
+fn skip_overflow() {
+    assert_eq!(
+        try_range_skip(0..u8::MAX, 1).unwrap_err(),
+        CyclicError::Overflow { start: 0, end: u8::MAX, step: 1 }
+    );
+    assert_eq!(
+        try_range_skip(i8::MIN..i8::MAX, 1).unwrap_err(),
+        CyclicError::Overflow { start: i8::MIN, end: i8::MAX, step: 1 }
+    );
+}
+
+#[test]
+fn step_ok_eq_range_step() {
+    assert_eq!(
+        try_range_step(i8::MIN, -124, 51).unwrap().collect::<Vec<_>>(),
+        range_step(i8::MIN, -124, 51).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn step_zero() {
+    assert_eq!(try_range_step(0_u8, 0, 0).unwrap_err(), CyclicError::BlockWiderThanStep { stop: 0, step: 0 });
+}
+
+#[test]
+fn step_inverted_bounds() {
+    assert_eq!(try_range_step(5_u8, 1, 20).unwrap_err(), CyclicError::InvertedBounds { start: 5, end: 1 });
+}
+
+#[test]
+fn step_overflow() {
+    assert_eq!(try_range_step(0_u8, 5, 257).unwrap_err(), CyclicError::Overflow { start: 0, end: 5, step: 257 });
+}
+
+#[test]
+fn idx_ok_eq_range_step_idx() {
+    assert_eq!(
+        try_range_step_idx(0, 2, 7, 22).unwrap().collect::<Vec<_>>(),
+        range_step_idx(0, 2, 7, 22).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn idx_errors() {
+    assert_eq!(try_range_step_idx(1, 0, 5, 255).unwrap_err(), CyclicError::InvertedBounds { start: 1, end: 0 });
+    assert_eq!(try_range_step_idx(0, 6, 5, 255).unwrap_err(), CyclicError::BlockWiderThanStep { stop: 6, step: 5 });
+    assert_eq!(try_range_step_idx(0, 6, 256, 255).unwrap_err(), CyclicError::StepExceedsLength { step: 256, len: 255 });
+}
+
+#[test]
+fn error_trait_object() {
+    let err: Box<dyn std::error::Error> = Box::new(try_range_step_idx(1, 0, 5, 255).unwrap_err());
+    assert_eq!(err.to_string(), "start 1 > end 0");
+}
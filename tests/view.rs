@@ -0,0 +1,113 @@
+use iter_cyclic::{range_step_idx, CyclicError, StridedView, StridedViewMut};
+
+#[test]
+fn rows_are_range_step_idx_blocks() {
+    let data: Vec<u16> = (0..100).collect();
+    let view = StridedView::new(&data, 0, [7, 3], [14, 1]);
+    assert_eq!(
+        view.indices().collect::<Vec<_>>(),
+        range_step_idx(0, 2, 14, 7 * 14).collect::<Vec<_>>()
+    );
+    assert_eq!(view.len(), 21);
+}
+
+#[test]
+fn three_dimensions() {
+    // 2 frames of 3x4 pixels with 2 channels, the channel 1 of the columns 1..3.
+    let data: Vec<u8> = (0..48).collect();
+    let view = StridedView::new(&data, 3, [2, 3, 2], [24, 8, 2]);
+    assert_eq!(view.shape(), [2, 3, 2]);
+    assert_eq!(view.to_vec(), [3, 5, 11, 13, 19, 21, 27, 29, 35, 37, 43, 45]);
+    assert_eq!(view[[1, 2, 1]], 45);
+    assert_eq!(view.get([1, 3, 0]), None);
+    assert_eq!(view.iter().rev().nth(1), Some(&43));
+}
+
+#[test]
+fn slice_of_slice() {
+    let data: Vec<u8> = (0..30).collect();
+    let view = StridedView::new(&data, 0, [5, 6], [6, 1]);
+    let region = view.slice([1..4, 2..6]).slice([1..3, 0..2]);
+    assert_eq!(region.offset(), 14);
+    assert_eq!(region.to_vec(), [14, 15, 20, 21]);
+    assert!(view.slice([5..5, 0..6]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "range 2..7 is out of bounds of axis 1 with length 6")]
+fn slice_out_of_shape() {
+    let data = [0_u8; 30];
+    StridedView::new(&data, 0, [5, 6], [6, 1]).slice([0..1, 2..7]);
+}
+
+#[test]
+#[should_panic(expected = "index 5 is out of bounds of axis 0 with length 5")]
+fn index_out_of_shape() {
+    let data = [0_u8; 30];
+    let _ = StridedView::new(&data, 0, [5, 6], [6, 1])[[5, 0]];
+}
+
+#[test]
+fn out_of_bounds() {
+    let data = [0_u8; 30];
+    assert_eq!(
+        StridedView::try_new(&data, 1, [5, 6], [6, 1]).unwrap_err(),
+        CyclicError::ViewOutOfBounds { last: 30, len: 30 }
+    );
+    assert_eq!(
+        StridedView::try_new(&data, 0, [2, 2], [usize::MAX, 1]).unwrap_err(),
+        CyclicError::ViewOutOfBounds { last: usize::MAX, len: 30 }
+    );
+    assert!(StridedView::try_new(&data, 100, [0, 6], [6, 1]).is_ok());
+}
+
+#[test]
+fn broadcast_zero_stride() {
+    let data = [1_u8, 2, 3];
+    let view = StridedView::new(&data, 0, [2, 3], [0, 1]);
+    assert_eq!(view.to_vec(), [1, 2, 3, 1, 2, 3]);
+}
+
+#[test]
+fn indices_skip_planes() {
+    let data: Vec<u8> = (0..64).collect();
+    let mut indices = StridedView::new(&data, 1, [2, 3, 2], [30, 8, 1]).indices();
+    assert_eq!(indices.nth(7), Some(32));
+    assert_eq!(indices.nth_back(2), Some(40));
+    assert_eq!(indices.len(), 1);
+    assert_eq!(indices.collect::<Vec<_>>(), [39]);
+
+    // The overlapping rows and the repeated columns are not a pattern of blocks.
+    let overlap = StridedView::new(&data, 0, [3, 4], [2, 1]);
+    assert_eq!(overlap.indices().rev().nth(4), Some(5));
+    let repeat = StridedView::new(&data, 5, [2, 3], [1, 0]);
+    assert_eq!(repeat.to_vec(), [5, 5, 5, 6, 6, 6]);
+}
+
+#[test]
+fn fill_and_index_mut() {
+    let mut data = vec![0_u8; 12];
+    let mut view = StridedViewMut::new(&mut data, 0, [3, 2], [4, 2]);
+    view.fill(7);
+    view[[2, 1]] = 9;
+    view.slice_mut([0..1, 0..2]).for_each_mut(|elem| *elem += 1);
+    assert_eq!(view.iter().copied().collect::<Vec<_>>(), [8, 8, 7, 7, 7, 9]);
+    assert_eq!(data, [8, 0, 8, 0, 7, 0, 7, 0, 7, 0, 9, 0]);
+}
+
+#[test]
+fn copy_from() {
+    let src: Vec<u8> = (0..12).collect();
+    let mut dst = vec![0_u8; 12];
+    let mut view = StridedViewMut::new(&mut dst, 0, [4, 3], [1, 4]);
+    view.copy_from(&StridedView::new(&src, 0, [4, 3], [3, 1]));
+    assert_eq!(dst, [0, 3, 6, 9, 1, 4, 7, 10, 2, 5, 8, 11]);
+}
+
+#[test]
+#[should_panic(expected = "source shape [3, 4] does not match destination shape [4, 3]")]
+fn copy_from_other_shape() {
+    let src = [0_u8; 12];
+    let mut dst = [0_u8; 12];
+    StridedViewMut::new(&mut dst, 0, [4, 3], [3, 1]).copy_from(&StridedView::new(&src, 0, [3, 4], [4, 1]));
+}